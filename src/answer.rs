@@ -0,0 +1,99 @@
+use std::fmt;
+
+// the value a part produces; not every puzzle's answer fits in a u64
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+  Integer(u64),
+  Signed(i64),
+  BigInteger(u128),
+  String(String),
+}
+
+impl From<u32> for Answer {
+  fn from(value: u32) -> Answer {
+    Answer::Integer(value.into())
+  }
+}
+
+impl From<u64> for Answer {
+  fn from(value: u64) -> Answer {
+    Answer::Integer(value)
+  }
+}
+
+impl From<usize> for Answer {
+  fn from(value: usize) -> Answer {
+    Answer::Integer(value as u64)
+  }
+}
+
+impl From<i32> for Answer {
+  fn from(value: i32) -> Answer {
+    Answer::Signed(value.into())
+  }
+}
+
+impl From<i64> for Answer {
+  fn from(value: i64) -> Answer {
+    Answer::Signed(value)
+  }
+}
+
+impl From<u128> for Answer {
+  fn from(value: u128) -> Answer {
+    Answer::BigInteger(value)
+  }
+}
+
+impl From<String> for Answer {
+  fn from(value: String) -> Answer {
+    Answer::String(value)
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(value: &str) -> Answer {
+    Answer::String(value.to_string())
+  }
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Answer::Integer(value) => write!(f, "{}", value),
+      Answer::Signed(value) => write!(f, "{}", value),
+      Answer::BigInteger(value) => write!(f, "{}", value),
+      Answer::String(value) => write!(f, "{}", value),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from() {
+    assert_eq!(Answer::from(42_usize), Answer::Integer(42));
+    assert_eq!(Answer::from(-3_i32), Answer::Signed(-3));
+    assert_eq!(
+      Answer::from(u128::from(u64::MAX) + 1),
+      Answer::BigInteger(18_446_744_073_709_551_616)
+    );
+    assert_eq!(Answer::from("abc"), Answer::String("abc".to_string()));
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!(Answer::Integer(144_554_112).to_string(), "144554112");
+    assert_eq!(Answer::Signed(-99).to_string(), "-99");
+    assert_eq!(
+      Answer::BigInteger(u128::MAX).to_string(),
+      u128::MAX.to_string()
+    );
+    assert_eq!(
+      Answer::String("cafe,babe".to_string()).to_string(),
+      "cafe,babe"
+    );
+  }
+}
@@ -0,0 +1,186 @@
+use serde_json::json;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Repeat {
+  // run each part this many times
+  Times(usize),
+  // keep running each part until this much time has gone by (but at least once)
+  For(Duration),
+}
+
+impl Repeat {
+  pub fn wants_more(&self, runs: usize, elapsed: Duration) -> bool {
+    match self {
+      Repeat::Times(times) => runs < *times,
+      Repeat::For(budget) => runs == 0 || elapsed < *budget,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchConfig {
+  pub warmup: usize,
+  pub repeat: Repeat,
+}
+
+impl Default for BenchConfig {
+  fn default() -> BenchConfig {
+    BenchConfig {
+      warmup: 3,
+      repeat: Repeat::Times(10),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+  pub runs: usize,
+  pub min: Duration,
+  pub median: Duration,
+  pub mean: Duration,
+  pub stddev: Duration,
+}
+
+impl Stats {
+  pub fn from_samples(samples: &[Duration]) -> Stats {
+    if samples.is_empty() {
+      return Stats {
+        runs: 0,
+        min: Duration::new(0, 0),
+        median: Duration::new(0, 0),
+        mean: Duration::new(0, 0),
+        stddev: Duration::new(0, 0),
+      };
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+
+    let runs = sorted.len();
+    let median = if runs % 2 == 1 {
+      sorted[runs / 2]
+    } else {
+      (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+    };
+
+    let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+    let mean = nanos.iter().sum::<f64>() / runs as f64;
+    // sample standard deviation; a single run doesn't have one
+    let variance = if runs > 1 {
+      nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+    } else {
+      0.0
+    };
+
+    Stats {
+      runs,
+      min: sorted[0],
+      median,
+      mean: Duration::from_nanos(mean.round() as u64),
+      stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+    }
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    json!({
+      "runs": self.runs,
+      "min_ns": self.min.as_nanos() as u64,
+      "median_ns": self.median.as_nanos() as u64,
+      "mean_ns": self.mean.as_nanos() as u64,
+      "stddev_ns": self.stddev.as_nanos() as u64,
+    })
+  }
+}
+
+impl fmt::Display for Stats {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{} runs, min {}, median {}, mean {}, stddev {}",
+      self.runs,
+      format_duration(self.min),
+      format_duration(self.median),
+      format_duration(self.mean),
+      format_duration(self.stddev)
+    )
+  }
+}
+
+// keeps the fractional part, so that 1.5 ms doesn't come out as 1 ms
+pub fn format_duration(duration: Duration) -> String {
+  let nanos = duration.as_nanos() as f64;
+
+  if nanos >= 1e9 {
+    format!("{:.3} s", nanos / 1e9)
+  } else if nanos >= 1e6 {
+    format!("{:.3} ms", nanos / 1e6)
+  } else if nanos >= 1e3 {
+    format!("{:.3} µs", nanos / 1e3)
+  } else {
+    format!("{} ns", nanos)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_repeat() {
+    assert!(Repeat::Times(2).wants_more(1, Duration::from_secs(10)));
+    assert!(!Repeat::Times(2).wants_more(2, Duration::from_secs(0)));
+    assert!(Repeat::For(Duration::from_secs(1)).wants_more(0, Duration::from_secs(5)));
+    assert!(Repeat::For(Duration::from_secs(1)).wants_more(9, Duration::from_millis(999)));
+    assert!(!Repeat::For(Duration::from_secs(1)).wants_more(9, Duration::from_secs(1)));
+  }
+
+  #[test]
+  fn test_stats_from_samples() {
+    let stats = Stats::from_samples(&[
+      Duration::from_micros(4),
+      Duration::from_micros(2),
+      Duration::from_micros(9),
+      Duration::from_micros(5),
+    ]);
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.min, Duration::from_micros(2));
+    assert_eq!(stats.median, Duration::from_nanos(4500));
+    assert_eq!(stats.mean, Duration::from_micros(5));
+    // sqrt(26 / 3) µs
+    assert_eq!(stats.stddev, Duration::from_nanos(2944));
+
+    let stats = Stats::from_samples(&[Duration::from_millis(3)]);
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.stddev, Duration::new(0, 0));
+
+    assert_eq!(Stats::from_samples(&[]).runs, 0);
+  }
+
+  #[test]
+  fn test_to_json() {
+    let stats = Stats::from_samples(&[Duration::from_micros(1), Duration::from_micros(3)]);
+    assert_eq!(
+      stats.to_json(),
+      json!({
+        "runs": 2,
+        "min_ns": 1000,
+        "median_ns": 2000,
+        "mean_ns": 2000,
+        "stddev_ns": 1414,
+      })
+    );
+  }
+
+  #[test]
+  fn test_format_duration() {
+    assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+    assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.234 µs");
+    assert_eq!(
+      format_duration(Duration::from_nanos(12_345_678)),
+      "12.346 ms"
+    );
+    assert_eq!(format_duration(Duration::from_millis(2_500)), "2.500 s");
+  }
+}
@@ -0,0 +1,582 @@
+use common::days::{self, Entry};
+use common::history::{self, Record, HISTORY};
+use common::inputs::{Fetcher, InputCache};
+use common::submit::{Submitter, Verdict, SUBMISSIONS};
+use common::watch::{self, DayRun, Watcher};
+use common::{pool, scaffold};
+use common::{AocError, BenchConfig, Day, DayId, ExpectedAnswers, Format, InputSource, Repeat};
+use std::env;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: common::memory::CountingAllocator = common::memory::CountingAllocator;
+
+const DATA: &str = "data";
+
+const USAGE: &str =
+  "usage: aoc run <days> [<input>] [--threads <n> | --serial] [--timeout <seconds>]
+               [--format <format>]
+       aoc bench <days> [<input>] [--warmup <n>] [--runs <n> | --time <seconds>]
+                 [--threads <n> | --serial] [--timeout <seconds>] [--format <format>]
+       aoc compare [<days>] [--baseline <commit>] [--threshold <percent>]
+       aoc new <day>
+       aoc submit <day> <part> [<answer>]
+       aoc watch <day> [--interval <seconds>] [--release] [--timeout <seconds>]
+
+<days> is one of:
+  7          a single day
+  1..=18     an inclusive range of days
+  1..19      an exclusive range of days
+  1,3,5..=7  a comma-separated list of any of the above
+  all        every registered day, of every year
+any of which but all can start with a year, e.g. 2015/7 or 2015/all; otherwise they're days
+of the latest registered year
+
+<input> replaces the day's input file for a single day, and is one of:
+  --file <path>   read the puzzle input from another file
+  --input <text>  use <text> as the puzzle input, e.g. --input 0,3,6
+  --stdin         read the puzzle input from standard input
+
+<format> is text (the default), json, csv or markdown; --json is short for --format json
+
+run works on up to <n> days at once (default: one per CPU), but always reports them in day
+order; bench runs one day at a time unless --threads says otherwise, so that timings aren't
+skewed by other days running alongside. --serial is short for --threads 1
+
+--timeout gives up on a part (or a day's parse step) that takes longer than <seconds>, and
+reports it as timed out; when benchmarking, that's all of the part's runs together
+
+bench runs each part <n> times (default 10) or for <seconds>, after
+3 untimed warm-up runs, and reports min/median/mean/stddev
+
+each day's input is read from data/<year>/day-NN-input.txt (or, for 2020, from
+data/day-NN-input.txt if there's nothing there), which is downloaded first if it's missing
+and AOC_SESSION is set to an adventofcode.com session token
+
+every run and bench is added to data/history.jsonl (unless it's given --no-history, or any
+of <input>); compare looks at the days in the latest one, and reports any answer that's
+changed and anything that's slower by more than <percent> (default 10) than the last time
+the day was run, or the last time it was run at <commit>
+
+new starts a day: it writes src/days/y<year>/dayNN.rs with stubs for both parts and a test
+module, registers it in src/days/y<year>/mod.rs (starting the year's module if it's the
+first), and creates an empty data/<year>/day-NN-input.txt and
+data/<year>/examples/day-NN/example.txt for the puzzle input and the example, along with
+data/<year>/examples/day-NN/example.toml for the example's answers; an empty input is treated
+as if it weren't there
+
+watch runs a day, then runs it again (with cargo run, so that it's rebuilt first) whenever
+its source or input changes, checking for changes every <seconds> (default
+0.5); each run's answers and timings are shown next to the ones before
+
+submit sends an answer to adventofcode.com (or the day's own answer, if it's left out),
+using AOC_SESSION; everything sent is kept in data/submissions.json, so that no answer is
+sent that's already known to be wrong, or before the site would accept another one
+
+answers are checked against data/<year>/answers.toml if it exists, unless the day is given
+any of <input>, since those answers are for the day's own input";
+
+enum Mode {
+  Run,
+  Bench(BenchConfig),
+}
+
+struct Options {
+  mode: Mode,
+  format: Format,
+  // overrides the day's usual input file
+  input: Option<InputSource>,
+  threads: usize,
+  // how long each part gets
+  timeout: Option<Duration>,
+  // whether to add the run to HISTORY
+  history: bool,
+}
+
+pub fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+  if let ["new", day] = args[..] {
+    return new_day(day);
+  }
+  if let ["submit", day, part, ref answer @ ..] = args[..] {
+    match submit(day, part, answer) {
+      Ok(Verdict::Correct) => return,
+      Ok(_) => std::process::exit(1),
+      Err(message) => {
+        eprintln!("{}", message);
+        std::process::exit(2);
+      }
+    }
+  }
+  if let ["watch", day, ref options @ ..] = args[..] {
+    if let Err(message) = watch(day, options) {
+      eprintln!("{}", message);
+      std::process::exit(2);
+    }
+    return;
+  }
+  if let ["compare", ref options @ ..] = args[..] {
+    match compare(options) {
+      Ok(false) => return,
+      Ok(true) => std::process::exit(1),
+      Err(message) => {
+        eprintln!("{}", message);
+        std::process::exit(2);
+      }
+    }
+  }
+
+  let parsed = match args[..] {
+    ["run", spec, ref options @ ..] => {
+      parse_options(Mode::Run, options).map(|p| (spec.to_string(), p))
+    }
+    ["bench", spec, ref options @ ..] => {
+      parse_options(Mode::Bench(BenchConfig::default()), options).map(|p| (spec.to_string(), p))
+    }
+    _ => Err(USAGE.to_string()),
+  };
+  let (spec, options) = match parsed {
+    Ok(parsed) => parsed,
+    Err(message) => {
+      eprintln!("{}", message);
+      std::process::exit(2);
+    }
+  };
+  let format = options.format;
+
+  match days::select(&spec)
+    .and_then(|entries| match (&options.input, entries.len()) {
+      (Some(_), n) if n != 1 => {
+        Err("--file, --input and --stdin can only be used with a single day".to_string())
+      }
+      _ => Ok(entries),
+    })
+    .and_then(|entries| {
+      let mut years: Vec<u16> = entries.iter().map(|entry| entry.year).collect();
+      years.sort_unstable();
+      years.dedup();
+      ExpectedAnswers::load_years(&years).map(|expected| (entries, expected))
+    }) {
+    Ok((entries, expected)) => {
+      let mut failures = 0;
+      let mut results: Vec<Day> = vec![];
+
+      // results come back in the same order as the entries
+      let mut order = entries.clone().into_iter();
+      pool::run_in_order(
+        entries,
+        options.threads,
+        |entry| run(entry, &expected, &options),
+        |result| {
+          let entry = order.next().unwrap();
+          let day = match result {
+            Ok(Some(day)) => day,
+            Ok(None) => {
+              failures += 1;
+              return;
+            }
+            Err(message) => panicked(entry, &message),
+          };
+          if !day.passed() {
+            failures += 1;
+          }
+          results.push(day);
+          if format.is_streaming() {
+            print!("{}", format.render(&results[results.len() - 1..]));
+          }
+        },
+      );
+
+      if !format.is_streaming() {
+        print!("{}", format.render(&results));
+      }
+      if options.history {
+        record(&results);
+      }
+
+      if failures > 0 {
+        std::process::exit(1);
+      }
+    }
+    Err(message) => {
+      eprintln!("{}", message);
+      std::process::exit(2);
+    }
+  }
+}
+
+fn parse_options(mut mode: Mode, options: &[&str]) -> Result<Options, String> {
+  let mut format = Format::Text;
+  let mut input = None;
+  let mut threads = None;
+  let mut timeout = None;
+  let mut history = true;
+  let mut options = options.iter();
+
+  while let Some(option) = options.next() {
+    let mut value = || options.next().ok_or(format!("{} needs a value", option));
+
+    match (*option, &mut mode) {
+      ("--format", _) => format = value()?.parse()?,
+      ("--json", _) => format = Format::Json,
+      ("--file", _) => input = Some(InputSource::File(value()?.to_string())),
+      ("--input", _) => input = Some(InputSource::Inline(value()?.to_string())),
+      ("--stdin", _) => input = Some(InputSource::Stdin),
+      ("--threads", _) => match parse_number(option, value()?)? {
+        0 => return Err(format!("{} has to be at least 1", option)),
+        n => threads = Some(n),
+      },
+      ("--serial", _) => threads = Some(1),
+      ("--warmup", Mode::Bench(config)) => config.warmup = parse_number(option, value()?)?,
+      ("--runs", Mode::Bench(config)) => match parse_number(option, value()?)? {
+        0 => return Err(format!("{} has to be at least 1", option)),
+        runs => config.repeat = Repeat::Times(runs),
+      },
+      ("--time", Mode::Bench(config)) => {
+        config.repeat = Repeat::For(parse_seconds(option, value()?)?)
+      }
+      ("--timeout", _) => timeout = Some(parse_seconds(option, value()?)?),
+      ("--no-history", _) => history = false,
+      _ => return Err(format!("unknown option: {}\n\n{}", option, USAGE)),
+    }
+  }
+
+  let threads = threads.unwrap_or(match mode {
+    Mode::Run => pool::default_threads(),
+    Mode::Bench(_) => 1,
+  });
+
+  // a run on some other input can't be compared with the rest
+  let history = history && input.is_none();
+
+  Ok(Options {
+    mode,
+    format,
+    input,
+    threads,
+    timeout,
+    history,
+  })
+}
+
+fn parse_seconds(option: &str, value: &str) -> Result<Duration, String> {
+  let seconds: f64 = parse_number(option, value)?;
+  if !seconds.is_finite() || seconds < 0.0 {
+    return Err(format!(
+      "{} expects a number of seconds, not '{}'",
+      option, seconds
+    ));
+  }
+
+  Ok(Duration::from_secs_f64(seconds))
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, String> {
+  value
+    .parse::<T>()
+    .map_err(|_| format!("{} expects a number, not '{}'", option, value))
+}
+
+fn run(entry: &Entry, expected: &ExpectedAnswers, options: &Options) -> Option<Day> {
+  let source = options
+    .input
+    .clone()
+    .unwrap_or_else(|| default_input(entry));
+  let mut data: Vec<String> = vec![];
+
+  match source.load(&mut data) {
+    Ok(_) => {
+      let mut day = Day::new(entry.id(), entry.solver);
+      day.timeout = options.timeout;
+      day.settings = match entry.id().load_settings() {
+        Ok(settings) => settings,
+        Err(e) => {
+          eprintln!("cannot read {}: {}", entry.id().settings_path(), e);
+          return None;
+        }
+      };
+
+      match &options.mode {
+        Mode::Run => day.run(&data),
+        Mode::Bench(config) => day.bench(&data, config),
+      }
+      // the expected answers are for the day's own input, so anything else stays unknown
+      if options.input.is_none() {
+        day.check(expected);
+      }
+
+      Some(day)
+    }
+    Err(e) => {
+      eprintln!("cannot read {}: {}", source, e);
+      None
+    }
+  }
+}
+
+// a day that panicked somewhere along the way, with both parts failed; the panic itself has
+// already been reported on stderr
+fn panicked(entry: &Entry, message: &str) -> Day {
+  let mut day = Day::new(entry.id(), entry.solver);
+  let error = AocError::no_solution(format!("panicked: {}", message));
+  day.part_1.result = Err(error.clone());
+  day.part_2.result = Err(error);
+  day
+}
+
+fn record(results: &[Day]) {
+  let run = history::now();
+  let commit = history::commit();
+  let records: Vec<Record> = results
+    .iter()
+    .map(|day| Record::new(run, commit.as_deref(), day))
+    .collect();
+
+  if let Err(e) = history::append(HISTORY, &records) {
+    eprintln!("cannot add the run to {}: {}", HISTORY, e);
+  }
+}
+
+// true if anything changed
+fn compare(options: &[&str]) -> Result<bool, String> {
+  let (spec, options) = match options {
+    [spec, options @ ..] if !spec.starts_with("--") => (Some(*spec), options),
+    _ => (None, options),
+  };
+  let mut baseline = None;
+  let mut threshold = 10.0;
+  let mut options = options.iter();
+
+  while let Some(option) = options.next() {
+    let mut value = || options.next().ok_or(format!("{} needs a value", option));
+
+    match *option {
+      "--baseline" => baseline = Some(value()?.to_string()),
+      "--threshold" => threshold = parse_number::<f64>(option, value()?)?,
+      _ => return Err(format!("unknown option: {}\n\n{}", option, USAGE)),
+    }
+  }
+
+  let ids: Option<Vec<DayId>> = spec
+    .map(|spec| days::select(spec).map(|entries| entries.iter().map(|e| e.id()).collect()))
+    .transpose()?;
+  let records = history::load(HISTORY)?;
+  let latest = history::latest(&records);
+  if latest.is_empty() {
+    return Err(format!("there aren't any runs in {} yet", HISTORY));
+  }
+
+  let mut changed = false;
+  for record in latest {
+    if ids.as_ref().is_some_and(|ids| !ids.contains(&record.id)) {
+      continue;
+    }
+
+    let old = match &baseline {
+      Some(commit) => history::at_commit(&records, record, commit),
+      None => history::previous(&records, record),
+    };
+    let old = match old {
+      Some(old) => old,
+      None => {
+        println!(
+          "{} day {:02}: nothing to compare with",
+          record.id.year, record.id.day
+        );
+        continue;
+      }
+    };
+    let at = old.commit.as_deref().unwrap_or("an unknown commit");
+
+    let changes = history::compare(old, record, threshold / 100.0);
+    if changes.is_empty() {
+      println!(
+        "{} day {:02}: no changes since {}",
+        record.id.year, record.id.day, at
+      );
+    } else {
+      changed = true;
+      println!("{} day {:02}: since {}", record.id.year, record.id.day, at);
+      for change in changes {
+        println!("  {}", change);
+      }
+    }
+  }
+
+  Ok(changed)
+}
+
+fn single_day(spec: &str) -> Result<&'static Entry, String> {
+  days::select(spec).and_then(|entries| match entries[..] {
+    [entry] => Ok(entry),
+    _ => Err(format!("expected a single day, not '{}'", spec)),
+  })
+}
+
+// only returns if something goes wrong before the first run
+fn watch(day: &str, options: &[&str]) -> Result<(), String> {
+  let entry = single_day(day)?;
+  let mut interval = Duration::from_millis(500);
+  let mut cargo = vec!["run".to_string(), "--quiet".to_string()];
+  let mut aoc = vec![
+    "run".to_string(),
+    format!("{}/{}", entry.year, entry.day),
+    "--format".to_string(),
+    "json".to_string(),
+    "--no-history".to_string(),
+  ];
+  let mut options = options.iter();
+
+  while let Some(option) = options.next() {
+    let mut value = || options.next().ok_or(format!("{} needs a value", option));
+
+    match *option {
+      "--interval" => interval = parse_seconds(option, value()?)?,
+      "--release" => cargo.push(option.to_string()),
+      "--timeout" => {
+        let seconds = value()?;
+        parse_seconds(option, seconds)?;
+        aoc.extend([option.to_string(), seconds.to_string()]);
+      }
+      _ => return Err(format!("unknown option: {}\n\n{}", option, USAGE)),
+    }
+  }
+  cargo.extend(["--bin".to_string(), "aoc".to_string(), "--".to_string()]);
+  cargo.extend(aoc);
+
+  let mut watcher = Watcher::new(watch::sources(Path::new(""), entry.id())?);
+  let mut previous: Option<DayRun> = None;
+
+  loop {
+    match rerun(&cargo) {
+      Ok(run) => {
+        print!("{}", watch::report(previous.as_ref(), &run));
+        previous = Some(run);
+      }
+      Err(message) => eprintln!("{}", message),
+    }
+
+    let paths: Vec<String> = watcher
+      .paths()
+      .iter()
+      .map(|path| path.display().to_string())
+      .collect();
+    println!("watching {}", paths.join(", "));
+
+    loop {
+      thread::sleep(interval);
+      let changed = watcher.changed();
+      if !changed.is_empty() {
+        for path in changed {
+          println!("{} changed", path.display());
+        }
+        break;
+      }
+    }
+  }
+}
+
+// builds and runs the day in a separate process, since a day that doesn't compile can't be run
+// in this one
+fn rerun(args: &[String]) -> Result<DayRun, String> {
+  let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+  let output = Command::new(&cargo)
+    .args(args)
+    .stderr(Stdio::inherit())
+    .output()
+    .map_err(|e| format!("cannot run {}: {}", cargo, e))?;
+  let stdout = String::from_utf8_lossy(&output.stdout);
+
+  // aoc run exits with 1 for a wrong answer, but still reports the day
+  match DayRun::parse(&stdout) {
+    Ok(run) => Ok(run),
+    Err(_) if !output.status.success() => Err(format!(
+      "the day didn't build or run ({}); waiting for changes",
+      output.status
+    )),
+    Err(e) => Err(format!("cannot make sense of the run: {}", e)),
+  }
+}
+
+fn submit(day: &str, part: &str, answer: &[&str]) -> Result<Verdict, String> {
+  let entry = single_day(day)?;
+  let part = match part {
+    "1" => 1,
+    "2" => 2,
+    _ => return Err(format!("expected part 1 or 2, not '{}'", part)),
+  };
+
+  let answer = match answer {
+    [answer] => answer.to_string(),
+    [] => {
+      let options = parse_options(Mode::Run, &[])?;
+      let day = run(entry, &ExpectedAnswers::default(), &options)
+        .ok_or(format!("cannot run {}", entry.id()))?;
+      let result = if part == 1 {
+        &day.part_1.result
+      } else {
+        &day.part_2.result
+      };
+      match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => return Err(format!("{} part {} failed: {}", entry.id(), part, e)),
+      }
+    }
+    _ => return Err(USAGE.to_string()),
+  };
+
+  let verdict = Submitter::from_env(SUBMISSIONS)?.submit(entry.year, entry.day, part, &answer)?;
+  println!(
+    "{} day {:02} part {}: {} is {}",
+    entry.year, entry.day, part, answer, verdict
+  );
+
+  Ok(verdict)
+}
+
+fn new_day(day: &str) {
+  let created = days::parse_id(day)
+    .and_then(|id| scaffold::new_day(Path::new(""), id).map(|paths| (id, paths)));
+
+  match created {
+    Ok((id, paths)) => {
+      for path in paths {
+        println!("created {}", path.display());
+      }
+      println!("registered {} in src/days/y{}/mod.rs", id, id.year);
+    }
+    Err(message) => {
+      eprintln!("{}", message);
+      std::process::exit(2);
+    }
+  }
+}
+
+// fills in a missing input if there's a session token to download it with
+fn default_input(entry: &Entry) -> InputSource {
+  let id = entry.id();
+  if entry.input.is_some() || id.find_input().is_some() {
+    return entry.input_source();
+  }
+  let fetcher = match Fetcher::from_env() {
+    Some(fetcher) => fetcher,
+    None => return entry.input_source(),
+  };
+
+  match InputCache::new(DATA).fetch_missing(id.year, id.day, &fetcher) {
+    Ok(path) => {
+      eprintln!("fetched {}", path.display());
+      InputSource::File(path.display().to_string())
+    }
+    Err(e) => {
+      eprintln!("cannot fetch the input for {}: {}", id, e);
+      entry.input_source()
+    }
+  }
+}
@@ -1,84 +1,520 @@
+use serde_json::json;
 use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
 use std::io::BufReader;
+use std::sync::mpsc;
+use std::thread;
 use std::time::{Duration, Instant};
 
+mod answer;
+mod bench;
+pub mod days;
+mod error;
+pub mod examples;
+mod expected;
+pub mod grid;
+pub mod history;
+mod id;
+mod input;
+pub mod inputs;
+pub mod ksum;
+pub mod memory;
+#[cfg(test)]
+mod mock;
+pub mod pool;
+mod report;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+pub mod watch;
+
+pub use answer::Answer;
+pub use bench::{format_duration, BenchConfig, Repeat, Stats};
+pub use error::AocError;
+pub use expected::{ExpectedAnswers, Status};
+pub use id::{data_dir, DayId};
+pub use input::{Input, Section};
+pub use memory::Memory;
+pub use report::Format;
+pub use solution::{solve, Solution, SolveFn, Solver};
+
 //*********************************************************
 // related to running days
 //
 
-pub type PartFn = fn(&[&str]) -> u64;
+pub type PartFn = fn(&[&str]) -> Result<Answer, AocError>;
+
+// what measure() found out about a step
+struct Measured<T> {
+  result: Result<T, AocError>,
+  duration: Duration,
+  stats: Option<Stats>,
+  memory: Option<Memory>,
+}
+
+// runs f once, or as many times as config asks for; when benchmarking, the duration is the
+// median of the timed runs, and the memory use is that of the first one
+fn measure<T>(
+  mut f: impl FnMut() -> Result<T, AocError>,
+  config: Option<&BenchConfig>,
+) -> Measured<T> {
+  let mut timed = || {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+  };
+
+  let config = match config {
+    Some(config) => config,
+    None => {
+      let ((result, duration), memory) = memory::count_allocations(timed);
+      return Measured {
+        result,
+        duration,
+        stats: None,
+        memory,
+      };
+    }
+  };
+
+  for _ in 0..config.warmup {
+    timed().0.ok();
+  }
+
+  let mut samples: Vec<Duration> = vec![];
+  let start = Instant::now();
+  let ((mut result, duration), memory) = memory::count_allocations(&mut timed);
+  samples.push(duration);
+  // there's no point timing something that doesn't produce an answer
+  while result.is_ok() && config.repeat.wants_more(samples.len(), start.elapsed()) {
+    let (next, duration) = timed();
+    result = next;
+    samples.push(duration);
+  }
+
+  let stats = Stats::from_samples(&samples);
+  Measured {
+    result,
+    duration: stats.median,
+    stats: Some(stats),
+    memory,
+  }
+}
+
+// the parse step of a Solution, timed separately from the parts
+#[derive(Clone)]
+pub struct Parse {
+  pub error: Option<AocError>,
+  pub stats: Option<Stats>,
+  // only if allocations are being counted; see memory::CountingAllocator
+  pub memory: Option<Memory>,
+  duration: Duration,
+}
+
+impl Parse {
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    let mut value = json!({
+      "error": self.error.as_ref().map(AocError::to_string),
+      "duration_ns": self.duration.as_nanos() as u64,
+    });
+    if let Some(stats) = &self.stats {
+      value["stats"] = stats.to_json();
+    }
+    if let Some(memory) = &self.memory {
+      value["memory"] = memory.to_json();
+    }
+
+    value
+  }
+}
 
+#[derive(Clone)]
 pub struct Part {
-  fun: PartFn,
-  pub result: u64,
+  pub result: Result<Answer, AocError>,
+  pub status: Status,
+  pub stats: Option<Stats>,
+  // only if allocations are being counted; see memory::CountingAllocator
+  pub memory: Option<Memory>,
   duration: Duration,
 }
 
 impl Part {
-  pub fn new(fun: PartFn) -> Part {
+  fn new() -> Part {
     Part {
-      fun,
-      result: 0,
+      result: Ok(Answer::Integer(0)),
+      status: Status::Unknown,
+      stats: None,
+      memory: None,
       duration: Duration::new(0, 0),
     }
   }
+
+  fn measure(&mut self, f: impl FnMut() -> Result<Answer, AocError>, config: Option<&BenchConfig>) {
+    let measured = measure(f, config);
+    self.result = measured.result;
+    self.duration = measured.duration;
+    self.stats = measured.stats;
+    self.memory = measured.memory;
+  }
+
+  // answers are compared by how they print, so an expected "1675" matches Signed(1675) or Integer(1675)
+  pub fn check(&mut self, expected: Option<&str>) {
+    self.status = match (&self.result, expected) {
+      (Ok(answer), Some(expected)) if answer.to_string() == expected => Status::Correct,
+      (Ok(_), Some(expected)) => Status::Wrong(expected.to_string()),
+      _ => Status::Unknown,
+    };
+  }
+
+  // true if the part returned an error or an answer known to be wrong
+  pub fn failed(&self) -> bool {
+    self.result.is_err() || matches!(self.status, Status::Wrong(_))
+  }
+
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  // one word for reports: "correct", "wrong", "unknown", or "failed" or "timed out" if there's no
+  // answer at all
+  pub fn outcome(&self) -> &'static str {
+    match (&self.result, &self.status) {
+      (Err(AocError::TimedOut(_)), _) => "timed out",
+      (Err(_), _) => "failed",
+      (Ok(_), Status::Correct) => "correct",
+      (Ok(_), Status::Wrong(_)) => "wrong",
+      (Ok(_), Status::Unknown) => "unknown",
+    }
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    let mut value = json!({
+      "answer": self.result.as_ref().ok().map(Answer::to_string),
+      "error": self.result.as_ref().err().map(AocError::to_string),
+      "status": self.outcome(),
+      "duration_ns": self.duration.as_nanos() as u64,
+    });
+    if let Some(stats) = &self.stats {
+      value["stats"] = stats.to_json();
+    }
+    if let Some(memory) = &self.memory {
+      value["memory"] = memory.to_json();
+    }
+
+    value
+  }
 }
 
 pub struct Day {
+  pub id: DayId,
+  solver: Solver,
+  // only for days that are a Solution
+  pub parse: Option<Parse>,
   pub part_1: Part,
   pub part_2: Part,
+  // how long each step (the parse or a part) gets before it's given up on; when benchmarking,
+  // that covers all of the step's runs
+  pub timeout: Option<Duration>,
+  // runs just this part and leaves the other one out, e.g. for an example that only applies to
+  // one of them
+  pub only: Option<u8>,
+  // the text of the day's settings file, passed to a Solution along with the input; see
+  // DayId::settings_path()
+  pub settings: Option<String>,
   duration: Duration,
+  // set when the day is running on a worker thread; see solve_on_worker()
+  progress: Option<mpsc::Sender<Step>>,
+}
+
+// what a worker thread sends back: each step as it finishes, then the whole day
+enum Step {
+  Parse(Parse),
+  Part(u8, Part),
+  Done(Box<Day>),
 }
 
 impl Day {
-  pub fn new(part_1: Part, part_2: Part) -> Day {
+  pub fn new(id: DayId, solver: Solver) -> Day {
     Day {
-      part_1,
-      part_2,
+      id,
+      solver,
+      parse: None,
+      part_1: Part::new(),
+      part_2: Part::new(),
+      timeout: None,
+      only: None,
+      settings: None,
       duration: Duration::new(0, 0),
+      progress: None,
     }
   }
 
   pub fn run(&mut self, data: &[String]) {
+    self.solve(data, None);
+  }
+
+  // runs each step repeatedly, one step at a time; see BenchConfig
+  pub fn bench(&mut self, data: &[String], config: &BenchConfig) {
+    self.solve(data, Some(config));
+  }
+
+  fn solve(&mut self, data: &[String], config: Option<&BenchConfig>) {
+    match self.timeout {
+      Some(timeout) => self.solve_on_worker(data, config, timeout),
+      None => self.solve_here(data, config),
+    }
+
+    self.duration = self
+      .parse
+      .as_ref()
+      .map_or(Duration::new(0, 0), Parse::duration)
+      + self.part_1.duration
+      + self.part_2.duration;
+  }
+
+  fn solve_here(&mut self, data: &[String], config: Option<&BenchConfig>) {
     let data_as_strs: Vec<&str> = data.iter().map(|v| v.as_str()).collect();
 
-    let start_part_1 = Instant::now();
-    self.part_1.result = (self.part_1.fun)(&data_as_strs);
-    self.part_1.duration = start_part_1.elapsed();
-    let start_part_2 = Instant::now();
-    self.part_2.result = (self.part_2.fun)(&data_as_strs);
-    self.part_2.duration = start_part_2.elapsed();
-    self.duration = start_part_1.elapsed();
+    match self.solver {
+      Solver::Parts(part_1, part_2) => {
+        self.measure_part(1, || part_1(&data_as_strs), config);
+        self.measure_part(2, || part_2(&data_as_strs), config);
+      }
+      Solver::Parsed(solve) => solve(self, &data_as_strs, config),
+    }
+  }
+
+  // a step that runs out of time can't be stopped, so it's left to carry on in the background
+  // (until the program exits) while the day reports what it has
+  fn solve_on_worker(&mut self, data: &[String], config: Option<&BenchConfig>, timeout: Duration) {
+    let (sender, receiver) = mpsc::channel();
+    let mut day = Day::new(self.id, self.solver);
+    day.only = self.only;
+    day.settings = self.settings.clone();
+    day.progress = Some(sender.clone());
+    let data = data.to_vec();
+    let config = config.cloned();
+
+    thread::spawn(move || {
+      day.solve_here(&data, config.as_ref());
+      day.progress = None;
+      sender.send(Step::Done(Box::new(day))).ok();
+    });
+
+    // 0 is the parse step
+    let mut running = match self.solver {
+      Solver::Parts(..) => 1,
+      Solver::Parsed(_) => 0,
+    };
+    loop {
+      let start = Instant::now();
+      match receiver.recv_timeout(timeout) {
+        Ok(Step::Parse(parse)) => {
+          self.parse = Some(parse);
+          running = 1;
+        }
+        Ok(Step::Part(n, part)) => {
+          *self.part_mut(n) = part;
+          running = n + 1;
+        }
+        Ok(Step::Done(day)) => {
+          let day = *day;
+          self.parse = day.parse;
+          self.part_1 = day.part_1;
+          self.part_2 = day.part_2;
+          return;
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+          return self.abandon(
+            running,
+            AocError::TimedOut(start.elapsed()),
+            start.elapsed(),
+          );
+        }
+        // the panic has already been reported on stderr
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+          let error = AocError::no_solution("panicked");
+          return self.abandon(running, error, start.elapsed());
+        }
+      }
+    }
+  }
+
+  // gives up on the running step, and the steps that would have come after it
+  fn abandon(&mut self, running: u8, error: AocError, elapsed: Duration) {
+    if running == 0 {
+      self.parse = Some(Parse {
+        error: Some(error.clone()),
+        stats: None,
+        memory: None,
+        duration: elapsed,
+      });
+      self.part_1.result = Err(error.clone());
+      self.part_2.result = Err(error);
+      return;
+    }
+
+    let part = self.part_mut(running);
+    part.result = Err(error);
+    part.stats = None;
+    part.memory = None;
+    part.duration = elapsed;
+    if running == 1 {
+      self.part_2.result = Err(AocError::no_solution(
+        "not run because part 1 didn't finish",
+      ));
+    }
+  }
+
+  fn part_mut(&mut self, n: u8) -> &mut Part {
+    match n {
+      1 => &mut self.part_1,
+      _ => &mut self.part_2,
+    }
+  }
+
+  fn finish_parse(&mut self, parse: Parse) {
+    if let Some(progress) = &self.progress {
+      progress.send(Step::Parse(parse.clone())).ok();
+    }
+    self.parse = Some(parse);
+  }
+
+  fn measure_part(
+    &mut self,
+    n: u8,
+    f: impl FnMut() -> Result<Answer, AocError>,
+    config: Option<&BenchConfig>,
+  ) {
+    match self.only {
+      Some(only) if only != n => self.part_mut(n).result = Err(AocError::no_solution("not run")),
+      _ => self.part_mut(n).measure(f, config),
+    }
+    if let Some(progress) = &self.progress {
+      let part = if n == 1 { &self.part_1 } else { &self.part_2 };
+      progress.send(Step::Part(n, part.clone())).ok();
+    }
+  }
+
+  pub fn check(&mut self, expected: &ExpectedAnswers) {
+    self.part_1.check(expected.get(self.id, 1));
+    self.part_2.check(expected.get(self.id, 2));
+  }
+
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  pub fn passed(&self) -> bool {
+    !self.part_1.failed() && !self.part_2.failed()
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    let mut value = json!({
+      "year": self.id.year,
+      "day": self.id.day,
+      "part_1": self.part_1.to_json(),
+      "part_2": self.part_2.to_json(),
+      "duration_ns": self.duration.as_nanos() as u64,
+      "passed": self.passed(),
+    });
+    if let Some(parse) = &self.parse {
+      value["parse"] = parse.to_json();
+    }
+
+    value
   }
 }
 
 impl fmt::Display for Day {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let (value_1, units_1) = scale_duration(self.part_1.duration);
-    let (value_2, units_2) = scale_duration(self.part_2.duration);
-    let (value_total, units_total) = scale_duration(self.duration);
+    if let Some(parse) = &self.parse {
+      let duration = format_duration(parse.duration);
+      match parse.error {
+        None => match &parse.memory {
+          Some(memory) => write!(f, "parse = {} ({}), ", duration, memory)?,
+          None => write!(f, "parse = {}, ", duration)?,
+        },
+        Some(AocError::TimedOut(_)) => write!(f, "parse timed out ({}), ", duration)?,
+        Some(_) => write!(f, "parse failed ({}), ", duration)?,
+      }
+    }
 
     f.write_fmt(format_args!(
-      "Time: part 1 = {} {}, part 2 = {} {}, total = {} {}",
-      value_1, units_1, value_2, units_2, value_total, units_total
-    ))
+      "part 1 = {}, part 2 = {}, total = {}",
+      self.part_1,
+      self.part_2,
+      format_duration(self.duration)
+    ))?;
+
+    // if the input couldn't be parsed, that's the only failure worth mentioning
+    if let Some(Parse { error: Some(e), .. }) = &self.parse {
+      let message = e.to_string().replace('\n', "\n  ");
+      return f.write_fmt(format_args!("\n  parse failed: {}", message));
+    }
+
+    for (n, part) in [(1, &self.part_1), (2, &self.part_2)].iter() {
+      if let Err(e) = &part.result {
+        let message = e.to_string().replace('\n', "\n  ");
+        f.write_fmt(format_args!("\n  part {} failed: {}", n, message))?;
+      }
+    }
+
+    Ok(())
   }
 }
 
-fn scale_duration(duration: Duration) -> (u128, &'static str) {
-  let nanos = duration.as_nanos();
+impl fmt::Display for Part {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let duration = format_duration(self.duration);
+    let memory = self
+      .memory
+      .map_or(String::new(), |memory| format!(", {}", memory));
 
-  if nanos >= 1_000_000_000 {
-    (duration.as_secs().into(), "s")
-  } else if nanos >= 1_000_000 {
-    (duration.as_millis(), "ms")
-  } else if nanos >= 1_000 {
-    (duration.as_micros(), "µs")
-  } else {
-    (nanos, "ns")
+    match &self.result {
+      Ok(answer) => write!(f, "{} ({}, {}{})", answer, self.status, duration, memory),
+      Err(AocError::TimedOut(_)) => write!(f, "timed out ({})", duration),
+      Err(_) => write!(f, "failed ({})", duration),
+    }
+  }
+}
+
+// where a day's puzzle input comes from
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+  File(String),
+  Stdin,
+  // e.g. from --input on the command line
+  Inline(String),
+  // e.g. from include_str!()
+  Embedded(&'static str),
+}
+
+impl InputSource {
+  pub fn load<'a>(&self, data: &'a mut Vec<String>) -> io::Result<&'a [String]> {
+    match self {
+      InputSource::File(filename) => load_data(filename, data),
+      InputSource::Stdin => load_data_from_reader(io::stdin().lock(), data),
+      InputSource::Inline(text) => load_data_from_reader(text.as_bytes(), data),
+      InputSource::Embedded(text) => load_data_from_reader(text.as_bytes(), data),
+    }
+  }
+}
+
+impl fmt::Display for InputSource {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      InputSource::File(filename) => write!(f, "{}", filename),
+      InputSource::Stdin => write!(f, "<stdin>"),
+      InputSource::Inline(_) => write!(f, "<inline input>"),
+      InputSource::Embedded(_) => write!(f, "<embedded input>"),
+    }
   }
 }
 
@@ -87,7 +523,10 @@ pub fn load_data<'a>(filename: &str, data: &'a mut Vec<String>) -> io::Result<&'
   load_data_from_reader(f, data)
 }
 
-fn load_data_from_reader<R: Read>(raw_reader: R, data: &mut Vec<String>) -> io::Result<&[String]> {
+pub fn load_data_from_reader<R: Read>(
+  raw_reader: R,
+  data: &mut Vec<String>,
+) -> io::Result<&[String]> {
   let reader = BufReader::new(raw_reader);
   for line in reader.lines() {
     match line {
@@ -98,10 +537,327 @@ fn load_data_from_reader<R: Read>(raw_reader: R, data: &mut Vec<String>) -> io::
   Ok(data)
 }
 
+// somewhere under the temp directory for a test to make a file or directory called name, with
+// nothing there yet; no two calls get the same path, even across test threads
+#[cfg(test)]
+pub(crate) fn scratch_path(name: &str) -> std::path::PathBuf {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  static COUNT: AtomicUsize = AtomicUsize::new(0);
+  let path = std::env::temp_dir().join(format!(
+    "aoc-{}-{}-{}",
+    std::process::id(),
+    COUNT.fetch_add(1, Ordering::SeqCst),
+    name
+  ));
+  let _ = std::fs::remove_dir_all(&path);
+  let _ = std::fs::remove_file(&path);
+
+  path
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_day_run_records_failures() {
+    fn count_lines(data: &[&str]) -> Result<Answer, AocError> {
+      Ok(data.len().into())
+    }
+
+    fn parse_lines(data: &[&str]) -> Result<Answer, AocError> {
+      data
+        .iter()
+        .enumerate()
+        .map(|(ix, line)| {
+          line
+            .parse::<u64>()
+            .map_err(|e| AocError::parse(ix, line, e.to_string()))
+        })
+        .sum::<Result<u64, _>>()
+        .map(Answer::from)
+    }
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(count_lines, parse_lines));
+    day.run(&["1".to_string(), "two".to_string()]);
+
+    assert_eq!(day.part_1.result, Ok(Answer::Integer(2)));
+    assert_eq!(
+      day.part_2.result,
+      Err(AocError::parse(1, "two", "invalid digit found in string"))
+    );
+    assert!(day.to_string().starts_with("part 1 = 2 (unknown, "));
+    assert!(day.to_string().contains("part 2 = failed ("));
+    assert!(day
+      .to_string()
+      .ends_with("part 2 failed: line 2: invalid digit found in string\n    two"));
+  }
+
+  #[test]
+  fn test_day_run_solution() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Numbers;
+
+    impl<'a> Solution<'a> for Numbers {
+      type Parsed = Vec<u64>;
+
+      fn parse(input: &Input<'a>) -> Result<Vec<u64>, AocError> {
+        PARSES.fetch_add(1, Ordering::SeqCst);
+        input.numbers()
+      }
+
+      fn part_1(numbers: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(Answer::from(numbers.iter().sum::<u64>()))
+      }
+
+      fn part_2(numbers: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(Answer::from(numbers.iter().product::<u64>()))
+      }
+    }
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parsed(solve::<Numbers>));
+    day.run(&["2".to_string(), "3".to_string()]);
+    assert_eq!(PARSES.load(Ordering::SeqCst), 1);
+    assert_eq!(day.part_1.result, Ok(Answer::Integer(5)));
+    assert_eq!(day.part_2.result, Ok(Answer::Integer(6)));
+    assert!(day.to_string().starts_with("parse = "));
+    assert!(day.to_string().contains(", part 1 = 5 (unknown, "));
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parsed(solve::<Numbers>));
+    day.run(&["2".to_string(), "three".to_string()]);
+    let error = AocError::parse(1, "three", "invalid digit found in string").at_column(0);
+    assert_eq!(day.parse.as_ref().unwrap().error, Some(error.clone()));
+    assert_eq!(day.part_1.result, Err(error.clone()));
+    assert_eq!(day.part_2.result, Err(error));
+    assert!(day.to_string().starts_with("parse failed ("));
+    assert!(!day.to_string().contains("part 1 failed"));
+    assert!(day.to_string().ends_with(
+      "parse failed: line 2, column 1: invalid digit found in string\n    three\n    ^"
+    ));
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parsed(solve::<Numbers>));
+    day.bench(
+      &["4".to_string()],
+      &BenchConfig {
+        warmup: 0,
+        repeat: Repeat::Times(3),
+      },
+    );
+    assert_eq!(day.parse.as_ref().unwrap().stats.as_ref().unwrap().runs, 3);
+    assert_eq!(day.part_1.stats.as_ref().unwrap().runs, 3);
+    assert_eq!(
+      day.duration(),
+      day.parse.as_ref().unwrap().duration() + day.part_1.duration() + day.part_2.duration()
+    );
+
+    // the same again, on a worker thread
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parsed(solve::<Numbers>));
+    day.timeout = Some(Duration::from_secs(10));
+    day.run(&["2".to_string(), "3".to_string()]);
+    assert_eq!(day.parse.as_ref().unwrap().error, None);
+    assert_eq!(day.part_2.result, Ok(Answer::Integer(6)));
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parsed(solve::<Numbers>));
+    day.timeout = Some(Duration::from_secs(10));
+    day.run(&["three".to_string()]);
+    assert!(day.parse.as_ref().unwrap().error.is_some());
+    assert_eq!(day.part_1.result, day.part_2.result);
+  }
+
+  #[test]
+  fn test_day_check() {
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Signed(42))
+    }
+
+    fn no_solution(_data: &[&str]) -> Result<Answer, AocError> {
+      Err(AocError::no_solution("nope"))
+    }
+
+    let expected =
+      ExpectedAnswers::from_toml(2020, "[day-01]\npart_1 = 42\n[day-02]\npart_1 = 41").unwrap();
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forty_two, no_solution));
+    day.run(&[]);
+    day.check(&expected);
+    assert_eq!(day.part_1.status, Status::Correct);
+    assert_eq!(day.part_2.status, Status::Unknown);
+    assert!(!day.part_1.failed());
+    assert!(day.part_2.failed());
+    assert!(day.to_string().starts_with("part 1 = 42 (correct, "));
+
+    day.id.day = 2;
+    day.check(&expected);
+    assert_eq!(day.part_1.status, Status::Wrong("41".to_string()));
+    assert!(day.part_1.failed());
+    assert!(day
+      .to_string()
+      .starts_with("part 1 = 42 (wrong, expected 41, "));
+
+    day.id.day = 3;
+    day.check(&expected);
+    assert_eq!(day.part_1.status, Status::Unknown);
+    assert!(!day.part_1.failed());
+  }
+
+  #[test]
+  fn test_day_only() {
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Integer(42))
+    }
+
+    fn forever(_data: &[&str]) -> Result<Answer, AocError> {
+      thread::sleep(Duration::from_secs(60));
+      Ok(Answer::Integer(0))
+    }
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forty_two, forever));
+    day.only = Some(1);
+    day.run(&[]);
+    assert_eq!(day.part_1.result, Ok(Answer::Integer(42)));
+    assert_eq!(day.part_2.result, Err(AocError::no_solution("not run")));
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forever, forty_two));
+    day.only = Some(2);
+    day.timeout = Some(Duration::from_secs(10));
+    day.run(&[]);
+    assert_eq!(day.part_1.result, Err(AocError::no_solution("not run")));
+    assert_eq!(day.part_2.result, Ok(Answer::Integer(42)));
+  }
+
+  #[test]
+  fn test_day_bench() {
+    fn count_lines(data: &[&str]) -> Result<Answer, AocError> {
+      Ok(data.len().into())
+    }
+
+    fn no_solution(_data: &[&str]) -> Result<Answer, AocError> {
+      Err(AocError::no_solution("nope"))
+    }
+
+    let config = BenchConfig {
+      warmup: 1,
+      repeat: Repeat::Times(5),
+    };
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(count_lines, no_solution));
+    day.bench(&["a".to_string(), "b".to_string()], &config);
+
+    assert_eq!(day.part_1.result, Ok(Answer::Integer(2)));
+    assert_eq!(day.part_1.stats.as_ref().unwrap().runs, 5);
+    assert_eq!(
+      day.part_1.duration,
+      day.part_1.stats.as_ref().unwrap().median
+    );
+    assert_eq!(day.part_2.stats.as_ref().unwrap().runs, 1);
+  }
+
+  #[test]
+  fn test_day_timeout() {
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Integer(42))
+    }
+
+    fn forever(_data: &[&str]) -> Result<Answer, AocError> {
+      thread::sleep(Duration::from_secs(60));
+      Ok(Answer::Integer(0))
+    }
+
+    fn panics(_data: &[&str]) -> Result<Answer, AocError> {
+      panic!("oops")
+    }
+
+    let timeout = Duration::from_millis(50);
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forty_two, forever));
+    day.timeout = Some(timeout);
+    let start = Instant::now();
+    day.run(&[]);
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(day.part_1.result, Ok(Answer::Integer(42)));
+    assert!(matches!(day.part_2.result, Err(AocError::TimedOut(elapsed)) if elapsed >= timeout));
+    assert!(day.part_2.duration() >= timeout);
+    assert_eq!(day.part_2.outcome(), "timed out");
+    assert!(!day.passed());
+    assert!(day.to_string().contains("part 2 = timed out ("));
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forever, forty_two));
+    day.timeout = Some(timeout);
+    day.run(&[]);
+    assert!(matches!(day.part_1.result, Err(AocError::TimedOut(_))));
+    assert_eq!(
+      day.part_2.result,
+      Err(AocError::no_solution(
+        "not run because part 1 didn't finish"
+      ))
+    );
+
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(panics, forty_two));
+    day.timeout = Some(Duration::from_secs(10));
+    day.run(&[]);
+    assert_eq!(day.part_1.result, Err(AocError::no_solution("panicked")));
+
+    // plenty of time, so it's as if there were no timeout
+    let mut day = Day::new(DayId::new(2020, 1), Solver::Parts(forty_two, forty_two));
+    day.timeout = Some(Duration::from_secs(10));
+    day.bench(
+      &[],
+      &BenchConfig {
+        warmup: 0,
+        repeat: Repeat::Times(3),
+      },
+    );
+    assert_eq!(day.part_2.result, Ok(Answer::Integer(42)));
+    assert_eq!(day.part_2.stats.as_ref().unwrap().runs, 3);
+    assert_eq!(
+      day.duration(),
+      day.part_1.duration() + day.part_2.duration()
+    );
+  }
+
+  #[test]
+  fn test_part_to_json() {
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Integer(42))
+    }
+
+    let mut part = Part::new();
+    part.result = forty_two(&[]);
+    part.check(Some("42"));
+    let value = part.to_json();
+    assert_eq!(value["answer"], "42");
+    assert_eq!(value["error"], serde_json::Value::Null);
+    assert_eq!(value["status"], "correct");
+    assert!(value.get("stats").is_none());
+
+    part.measure(|| forty_two(&[]), Some(&BenchConfig::default()));
+    assert_eq!(part.to_json()["stats"]["runs"], 10);
+  }
+
+  #[test]
+  fn test_input_source() {
+    let mut data = vec![];
+    assert_eq!(
+      InputSource::Inline("0,3,6".to_string())
+        .load(&mut data)
+        .unwrap(),
+      ["0,3,6"]
+    );
+
+    let mut data = vec![];
+    assert_eq!(
+      InputSource::Embedded("1\n2\n3\n").load(&mut data).unwrap(),
+      ["1", "2", "3"]
+    );
+
+    let mut data = vec![];
+    let source = InputSource::File("data/no-such-input.txt".to_string());
+    assert!(source.load(&mut data).is_err());
+    assert_eq!(source.to_string(), "data/no-such-input.txt");
+  }
+
   #[test]
   fn test_load_data_from_reader() {
     let raw_data = String::from("one\ntwo\nthree");
@@ -110,7 +866,7 @@ mod tests {
     match load_data_from_reader(raw_data.as_bytes(), &mut data) {
       Ok(v) => assert_eq!(
         (*v).iter().map(String::from).collect::<Vec<String>>(),
-        vec!["one", "two", "three"]
+        ["one", "two", "three"]
           .iter()
           .map(|item| item.to_string())
           .collect::<Vec<String>>()
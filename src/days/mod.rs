@@ -0,0 +1,169 @@
+use crate::{DayId, InputSource, Solver};
+
+// each year's days are in a module of their own, with a REGISTRY of them
+pub mod y2020;
+
+pub static YEARS: &[&[Entry]] = &[y2020::REGISTRY];
+
+pub struct Entry {
+  pub year: u16,
+  pub day: u8,
+  pub solver: Solver,
+  // for a day whose input is on the puzzle page rather than in a file to download
+  pub input: Option<&'static str>,
+}
+
+impl Entry {
+  pub fn id(&self) -> DayId {
+    DayId::new(self.year, self.day)
+  }
+
+  // the day's embedded input if it has one, otherwise its input file, wherever it is; see
+  // DayId::find_input()
+  pub fn input_source(&self) -> InputSource {
+    if let Some(text) = self.input {
+      return InputSource::Embedded(text);
+    }
+    let id = self.id();
+    InputSource::File(id.find_input().unwrap_or_else(|| id.input_path()))
+  }
+}
+
+// every registered day, in order of year and then day
+pub fn registry() -> impl Iterator<Item = &'static Entry> {
+  YEARS.iter().flat_map(|year| year.iter())
+}
+
+// the year that days without one belong to
+pub fn latest_year() -> u16 {
+  registry().map(|entry| entry.year).max().unwrap_or_default()
+}
+
+pub fn find(id: DayId) -> Option<&'static Entry> {
+  registry().find(|entry| entry.id() == id)
+}
+
+// accepts "all", a single day ("7"), a range ("1..=18" or "1..19"),
+// or a comma-separated list of any of those ("1,3,10..=12"); any of them but "all" can start
+// with a year ("2015/1..=5", "2015/all"), and otherwise they're days of the latest_year()
+// ("all" on its own is every day of every year)
+pub fn select(spec: &str) -> Result<Vec<&'static Entry>, String> {
+  if spec == "all" {
+    return Ok(registry().collect());
+  }
+
+  let (year, spec) = split_year(spec)?;
+  if spec == "all" {
+    let entries: Vec<&Entry> = registry().filter(|entry| entry.year == year).collect();
+    if entries.is_empty() {
+      return Err(format!("no solutions registered for {}", year));
+    }
+    return Ok(entries);
+  }
+
+  let mut days: Vec<u8> = vec![];
+
+  for item in spec.split(',').map(str::trim) {
+    if let Some(ix) = item.find("..=") {
+      days.extend(parse_day(&item[..ix])?..=parse_day(&item[ix + 3..])?);
+    } else if let Some(ix) = item.find("..") {
+      days.extend(parse_day(&item[..ix])?..parse_day(&item[ix + 2..])?);
+    } else {
+      days.push(parse_day(item)?);
+    }
+  }
+
+  days
+    .iter()
+    .map(|day| {
+      let id = DayId::new(year, *day);
+      find(id).ok_or(format!("no solution registered for {}", id))
+    })
+    .collect()
+}
+
+// a single day, which doesn't have to be registered yet, e.g. "7" or "2015/7"
+pub fn parse_id(spec: &str) -> Result<DayId, String> {
+  let (year, day) = split_year(spec)?;
+  Ok(DayId::new(year, parse_day(day)?))
+}
+
+fn split_year(spec: &str) -> Result<(u16, &str), String> {
+  match spec.split_once('/') {
+    Some((year, rest)) => year
+      .trim()
+      .parse::<u16>()
+      .map(|year| (year, rest))
+      .map_err(|_| format!("not a year: '{}'", year)),
+    None => Ok((latest_year(), spec)),
+  }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+  value
+    .parse::<u8>()
+    .map_err(|_| format!("not a day number: '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn days_of(entries: &[&Entry]) -> Vec<u8> {
+    entries.iter().map(|entry| entry.day).collect()
+  }
+
+  #[test]
+  fn test_find() {
+    assert_eq!(find(DayId::new(2020, 7)).unwrap().day, 7);
+    assert!(find(DayId::new(2020, 25)).is_none());
+    assert!(find(DayId::new(2015, 7)).is_none());
+  }
+
+  #[test]
+  fn test_registry() {
+    let ids: Vec<DayId> = registry().map(Entry::id).collect();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(ids, sorted);
+    assert_eq!(latest_year(), 2020);
+  }
+
+  #[test]
+  fn test_input_source() {
+    let day_15 = find(DayId::new(2020, 15)).unwrap();
+    assert!(matches!(day_15.input_source(), InputSource::Embedded(_)));
+    assert!(matches!(
+      find(DayId::new(2020, 7)).unwrap().input_source(),
+      InputSource::File(_)
+    ));
+  }
+
+  #[test]
+  fn test_select() {
+    assert_eq!(days_of(&select("7").unwrap()), vec![7]);
+    assert_eq!(days_of(&select("1..=3").unwrap()), vec![1, 2, 3]);
+    assert_eq!(days_of(&select("1..3").unwrap()), vec![1, 2]);
+    assert_eq!(days_of(&select("1,5..=6").unwrap()), vec![1, 5, 6]);
+    assert_eq!(days_of(&select("2020/1,5..=6").unwrap()), vec![1, 5, 6]);
+    assert_eq!(select("all").unwrap().len(), registry().count());
+    assert_eq!(select("2020/all").unwrap().len(), y2020::REGISTRY.len());
+
+    assert!(select("seven").is_err());
+    assert!(select("25").is_err());
+    assert_eq!(
+      select("2015/7").err(),
+      Some("no solution registered for 2015 day 7".to_string())
+    );
+    assert!(select("2015/all").is_err());
+    assert!(select("twenty/7").is_err());
+  }
+
+  #[test]
+  fn test_parse_id() {
+    assert_eq!(parse_id("7"), Ok(DayId::new(2020, 7)));
+    assert_eq!(parse_id("2015/25"), Ok(DayId::new(2015, 25)));
+    assert!(parse_id("2015/").is_err());
+  }
+}
@@ -0,0 +1,81 @@
+use crate::ksum;
+use crate::{Answer, AocError};
+use std::convert::TryFrom;
+
+const TARGET: i64 = 2020;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  // 462 * 1558 = 719_796
+  product_of_sum(data, 2, "two")
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  // 979 * 366 * 675 = 144_554_112
+  product_of_sum(data, 3, "three")
+}
+
+// the product of the first k entries found that sum to TARGET
+fn product_of_sum(data: &[&str], k: usize, how_many: &str) -> Result<Answer, AocError> {
+  let entries = parse(data)?;
+  let tuple = ksum::find(&entries, k, TARGET)
+    .ok_or_else(|| AocError::no_solution(format!("no {} entries sum to {}", how_many, TARGET)))?;
+
+  let product = tuple
+    .values
+    .iter()
+    .try_fold(1_i64, |product, &value| product.checked_mul(value))
+    .ok_or_else(|| AocError::no_solution("the product of the entries is too big"))?;
+  Ok(u64::try_from(product).map_or(Answer::from(product), Answer::from))
+}
+
+fn parse(data: &[&str]) -> Result<Vec<i64>, AocError> {
+  data
+    .iter()
+    .enumerate()
+    .filter(|(_, line)| !line.is_empty())
+    .map(|(ix, line)| {
+      line
+        .parse()
+        .map_err(|e: std::num::ParseIntError| AocError::parse(ix, line, e.to_string()))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-01/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    assert_eq!(part_1(&get_test_data()), Ok(Answer::Integer(514_579)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    assert_eq!(part_2(&get_test_data()), Ok(Answer::Integer(241_861_950)));
+  }
+
+  #[test]
+  fn test_negatives() {
+    assert_eq!(
+      part_2(&["2022", "-1", "1000", "-1"]),
+      Ok(Answer::Integer(2022))
+    );
+    assert_eq!(part_1(&["2021", "-1"]), Ok(Answer::Signed(-2021)));
+  }
+
+  #[test]
+  fn test_no_solution() {
+    assert_eq!(
+      part_1(&["1010", "1"]),
+      Err(AocError::no_solution("no two entries sum to 2020"))
+    );
+    assert!(part_1(&["1721", "x"]).is_err());
+  }
+}
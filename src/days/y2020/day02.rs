@@ -0,0 +1,321 @@
+use crate::{Answer, AocError};
+use regex::{Match, Regex};
+use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  count_valid(data, &Sled)
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  count_valid(data, &Toboggan)
+}
+
+// a line of the input, like "1-3 a: abcde"; what the two numbers mean is up to the policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+  pub first: usize,
+  pub second: usize,
+  pub letter: &'a str,
+  pub password: &'a str,
+}
+
+// the rules a password has to follow, given the numbers and the letter from its entry
+pub trait PasswordPolicy: Sync {
+  fn name(&self) -> &'static str;
+
+  // whether the entry's numbers make sense for this policy at all; checked before check()
+  fn accepts(&self, _entry: &Entry) -> Result<(), &'static str> {
+    Ok(())
+  }
+
+  fn check(&self, entry: &Entry) -> Result<(), Violation>;
+}
+
+// why a password doesn't follow a policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation {
+  TooFew { count: usize, min: usize },
+  TooMany { count: usize, max: usize },
+  AtBoth { first: usize, second: usize },
+  AtNeither { first: usize, second: usize },
+}
+
+impl fmt::Display for Violation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Violation::TooFew { count, min } => {
+        write!(f, "the letter appears {} times, fewer than {}", count, min)
+      }
+      Violation::TooMany { count, max } => {
+        write!(f, "the letter appears {} times, more than {}", count, max)
+      }
+      Violation::AtBoth { first, second } => write!(
+        f,
+        "the letter is at both positions {} and {}",
+        first, second
+      ),
+      Violation::AtNeither { first, second } => write!(
+        f,
+        "the letter is at neither position {} nor {}",
+        first, second
+      ),
+    }
+  }
+}
+
+// the letter has to appear between first and second times, inclusive
+pub struct Sled;
+
+impl PasswordPolicy for Sled {
+  fn name(&self) -> &'static str {
+    "sled"
+  }
+
+  fn check(&self, entry: &Entry) -> Result<(), Violation> {
+    let count = entry
+      .password
+      .graphemes(true)
+      .filter(|c| *c == entry.letter)
+      .count();
+
+    if count < entry.first {
+      Err(Violation::TooFew {
+        count,
+        min: entry.first,
+      })
+    } else if count > entry.second {
+      Err(Violation::TooMany {
+        count,
+        max: entry.second,
+      })
+    } else {
+      Ok(())
+    }
+  }
+}
+
+// the letter has to be at exactly one of the positions first and second, counting from 1
+pub struct Toboggan;
+
+impl PasswordPolicy for Toboggan {
+  fn name(&self) -> &'static str {
+    "toboggan"
+  }
+
+  fn accepts(&self, entry: &Entry) -> Result<(), &'static str> {
+    if entry.first == 0 || entry.second == 0 {
+      Err("positions start at 1")
+    } else {
+      Ok(())
+    }
+  }
+
+  fn check(&self, entry: &Entry) -> Result<(), Violation> {
+    let password = entry.password.graphemes(true).collect::<Vec<&str>>();
+    let at = |position: usize| password.get(position - 1) == Some(&entry.letter);
+    let (first, second) = (entry.first, entry.second);
+
+    match (at(first), at(second)) {
+      (true, false) | (false, true) => Ok(()),
+      (true, true) => Err(Violation::AtBoth { first, second }),
+      (false, false) => Err(Violation::AtNeither { first, second }),
+    }
+  }
+}
+
+pub static POLICIES: &[&dyn PasswordPolicy] = &[&Sled, &Toboggan];
+
+pub fn find_policy(name: &str) -> Option<&'static dyn PasswordPolicy> {
+  POLICIES
+    .iter()
+    .copied()
+    .find(|policy| policy.name() == name)
+}
+
+// what a policy made of one line of the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+  // 0-based, like the ix passed to AocError::parse()
+  pub ix: usize,
+  pub entry: Entry<'a>,
+  pub verdict: Result<(), Violation>,
+}
+
+impl fmt::Display for Diagnostic<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "line {}: {}: ", self.ix + 1, self.entry.password)?;
+    match &self.verdict {
+      Ok(()) => write!(f, "valid"),
+      Err(violation) => write!(f, "invalid, {}", violation),
+    }
+  }
+}
+
+// every line of the input checked against the policy
+pub fn diagnose<'a>(
+  data: &[&'a str],
+  policy: &dyn PasswordPolicy,
+) -> Result<Vec<Diagnostic<'a>>, AocError> {
+  let regex = entry_regex();
+  data
+    .iter()
+    .enumerate()
+    .map(|(ix, line)| {
+      let entry = parse_entry(&regex, ix, line)?;
+      policy
+        .accepts(&entry)
+        .map_err(|message| AocError::parse(ix, line, message))?;
+      Ok(Diagnostic {
+        ix,
+        entry,
+        verdict: policy.check(&entry),
+      })
+    })
+    .collect()
+}
+
+fn count_valid(data: &[&str], policy: &dyn PasswordPolicy) -> Result<Answer, AocError> {
+  let diagnostics = diagnose(data, policy)?;
+  Ok(Answer::from(
+    diagnostics
+      .iter()
+      .filter(|diagnostic| diagnostic.verdict.is_ok())
+      .count(),
+  ))
+}
+
+fn entry_regex() -> Regex {
+  Regex::new(r"(\d+)-(\d+)\s+([a-z]):\s+(\S+)").unwrap()
+}
+
+fn parse_entry<'a>(regex: &Regex, ix: usize, line: &'a str) -> Result<Entry<'a>, AocError> {
+  let captures = regex
+    .captures(line)
+    .ok_or_else(|| AocError::parse(ix, line, "expected a rule like '1-3 a: abcde'"))?;
+
+  Ok(Entry {
+    first: parse_number(ix, line, captures.get(1).unwrap())?,
+    second: parse_number(ix, line, captures.get(2).unwrap())?,
+    letter: captures.get(3).unwrap().as_str(),
+    password: captures.get(4).unwrap().as_str(),
+  })
+}
+
+fn parse_number(ix: usize, line: &str, m: Match) -> Result<usize, AocError> {
+  m.as_str()
+    .parse::<usize>()
+    .map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(m.start()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-02/example.txt")
+      .lines()
+      .collect()
+  }
+
+  fn entry<'a>(first: usize, second: usize, letter: &'a str, password: &'a str) -> Entry<'a> {
+    Entry {
+      first,
+      second,
+      letter,
+      password,
+    }
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(2)));
+
+    let data = vec!["1-3 a: abcde", "1-3 b cdefg"];
+    assert_eq!(
+      part_1(&data),
+      Err(AocError::parse(
+        1,
+        "1-3 b cdefg",
+        "expected a rule like '1-3 a: abcde'"
+      ))
+    );
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(1)));
+
+    assert_eq!(
+      part_2(&["0-3 a: abcde"]),
+      Err(AocError::parse(0, "0-3 a: abcde", "positions start at 1"))
+    );
+  }
+
+  #[test]
+  fn test_sled() {
+    assert_eq!(Sled.check(&entry(1, 3, "a", "abcde")), Ok(()));
+    assert_eq!(
+      Sled.check(&entry(1, 3, "b", "cdefg")),
+      Err(Violation::TooFew { count: 0, min: 1 })
+    );
+    assert_eq!(Sled.check(&entry(2, 9, "c", "ccccccccc")), Ok(()));
+    assert_eq!(
+      Sled.check(&entry(1, 2, "c", "ccc")),
+      Err(Violation::TooMany { count: 3, max: 2 })
+    );
+  }
+
+  #[test]
+  fn test_toboggan() {
+    assert_eq!(Toboggan.check(&entry(1, 3, "a", "abcde")), Ok(()));
+    assert_eq!(
+      Toboggan.check(&entry(1, 3, "b", "cdefg")),
+      Err(Violation::AtNeither {
+        first: 1,
+        second: 3
+      })
+    );
+    assert_eq!(
+      Toboggan.check(&entry(2, 9, "c", "ccccccccc")),
+      Err(Violation::AtBoth {
+        first: 2,
+        second: 9
+      })
+    );
+    // a position past the end of the password doesn't have the letter
+    assert_eq!(Toboggan.check(&entry(1, 9, "a", "abc")), Ok(()));
+  }
+
+  #[test]
+  fn test_registry() {
+    assert_eq!(
+      find_policy("sled").map(|policy| policy.name()),
+      Some("sled")
+    );
+    assert_eq!(
+      find_policy("toboggan").map(|policy| policy.name()),
+      Some("toboggan")
+    );
+    assert!(find_policy("sleigh").is_none());
+  }
+
+  #[test]
+  fn test_diagnose() {
+    let data = vec!["1-3 a: abcde", "1-3 b: cdefg"];
+    let diagnostics = diagnose(&data, &Sled).unwrap();
+    assert_eq!(diagnostics[0].entry, entry(1, 3, "a", "abcde"));
+    assert_eq!(
+      diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.to_string())
+        .collect::<Vec<_>>(),
+      vec![
+        "line 1: abcde: valid",
+        "line 2: cdefg: invalid, the letter appears 0 times, fewer than 1"
+      ]
+    );
+  }
+}
@@ -0,0 +1,180 @@
+use crate::grid::Grid;
+use crate::{Answer, AocError};
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let forest = parse_forest(data)?;
+
+  Ok(Answer::from(trees_in_path(&forest, (3, 1))?))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let forest = parse_forest(data)?;
+
+  let mut product = 1;
+  for &slope in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+    product *= trees_in_path(&forest, slope)? as u64;
+  }
+  Ok(Answer::Integer(product))
+}
+
+// the forest wraps around to the right, so a row needs at least one square to wrap around to
+pub fn parse_forest(data: &[&str]) -> Result<Grid<Square>, AocError> {
+  let forest = Grid::parse(data)?;
+  if forest.height() > 0 && forest.width() == 0 {
+    return Err(AocError::parse(
+      0,
+      data[0],
+      "a row of the forest can't be empty",
+    ));
+  }
+
+  Ok(forest)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Square {
+  Open,
+  Tree,
+}
+
+impl TryFrom<char> for Square {
+  type Error = ();
+
+  fn try_from(c: char) -> Result<Square, ()> {
+    match c {
+      '.' => Ok(Square::Open),
+      '#' => Ok(Square::Tree),
+      _ => Err(()),
+    }
+  }
+}
+
+// how far to go right (or left, if it's negative) and down with each step; down has to be at
+// least 1, or the path would never get to the bottom, which trees_in_path() reports as an error
+pub type Slope = (isize, usize);
+
+// the forest repeats to the right (and the left) forever, but not downwards: the path stops at
+// the bottom, so a slope with a down of more than the height only ever passes the top left
+pub fn trees_in_path(forest: &Grid<Square>, (right, down): Slope) -> Result<u32, AocError> {
+  if down == 0 {
+    return Err(AocError::no_solution(format!(
+      "the slope ({}, 0) never goes down",
+      right
+    )));
+  }
+
+  Ok(
+    (0..forest.height())
+      .step_by(down)
+      .enumerate()
+      .filter(|(step, y)| {
+        forest.get_wrapping((*step as isize * right, *y as isize)) == Some(&Square::Tree)
+      })
+      .count() as u32,
+  )
+}
+
+// every slope with a right in rights and a down in downs, with the trees on its path, fewest
+// trees first; slopes with as many trees as each other stay in order of right, then down
+pub fn rank_slopes(
+  forest: &Grid<Square>,
+  rights: RangeInclusive<isize>,
+  downs: RangeInclusive<usize>,
+) -> Result<Vec<(Slope, u32)>, AocError> {
+  let mut ranked: Vec<(Slope, u32)> = rights
+    .flat_map(|right| downs.clone().map(move |down| (right, down)))
+    .map(|slope| trees_in_path(forest, slope).map(|trees| (slope, trees)))
+    .collect::<Result<_, _>>()?;
+
+  ranked.sort_by_key(|&(_, trees)| trees);
+  Ok(ranked)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-03/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = get_test_data();
+
+    assert_eq!(part_1(&data), Ok(Answer::Integer(7)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = get_test_data();
+
+    assert_eq!(part_2(&data), Ok(Answer::Integer(336)));
+  }
+
+  #[test]
+  fn test_parse_forest() {
+    assert_eq!(
+      part_1(&[""]),
+      Err(AocError::parse(0, "", "a row of the forest can't be empty"))
+    );
+    assert_eq!(part_1(&[]), Ok(Answer::Integer(0)));
+    assert_eq!(
+      part_1(&["..#", ".O."]),
+      Err(AocError::parse(1, ".O.", "don't know what to do with 'O'").at_column(1))
+    );
+  }
+
+  #[test]
+  fn test_trees_in_path() {
+    let data = get_test_data();
+
+    let forest = parse_forest(&data).unwrap();
+    let trees = |slope| trees_in_path(&forest, slope).unwrap();
+
+    assert_eq!(trees((1, 1)), 2);
+    assert_eq!(trees((3, 1)), 7);
+    assert_eq!(trees((5, 1)), 3);
+    assert_eq!(trees((7, 1)), 4);
+    assert_eq!(trees((1, 2)), 2);
+
+    // steep, and off the left
+    assert_eq!(trees((0, 1)), 3);
+    assert_eq!(trees((1, 3)), 0);
+    assert_eq!(trees((-1, 1)), 5);
+    // only the top left, which is open
+    assert_eq!(trees((3, 11)), 0);
+    assert_eq!(trees((3, 100)), 0);
+  }
+
+  #[test]
+  fn test_rank_slopes() {
+    let data = get_test_data();
+
+    let forest = parse_forest(&data).unwrap();
+    let ranked = rank_slopes(&forest, 1..=7, 1..=1).unwrap();
+
+    assert_eq!(ranked.len(), 7);
+    assert_eq!(ranked.first(), Some(&((2, 1), 1)));
+    assert_eq!(ranked.last(), Some(&((3, 1), 7)));
+    assert_eq!(
+      ranked[1..6].to_vec(),
+      vec![
+        ((1, 1), 2),
+        ((4, 1), 2),
+        ((5, 1), 3),
+        ((6, 1), 3),
+        ((7, 1), 4)
+      ]
+    );
+
+    assert_eq!(
+      rank_slopes(&forest, 1..=7, 0..=1),
+      Err(AocError::no_solution("the slope (1, 0) never goes down"))
+    );
+  }
+}
@@ -0,0 +1,643 @@
+use crate::{Answer, AocError, Input, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+pub struct Day04;
+
+pub struct Passports<'a> {
+  schema: Schema,
+  records: Vec<HashMap<&'a str, &'a str>>,
+}
+
+impl<'a> Solution<'a> for Day04 {
+  type Parsed = Passports<'a>;
+
+  // the runner reads data/2020/day-04-settings.toml, if there is one, and passes it in with the
+  // input, so the rules can be changed without rebuilding
+  fn parse(input: &Input<'a>) -> Result<Passports<'a>, AocError> {
+    let schema = match input.settings() {
+      Some(text) => Schema::from_toml(text).map_err(AocError::Settings)?,
+      None => Schema::default(),
+    };
+    Ok(Passports {
+      schema,
+      records: records(input.lines())?,
+    })
+  }
+
+  fn part_1(passports: &Passports) -> Result<Answer, AocError> {
+    Ok(Answer::Integer(count_complete(
+      &passports.records,
+      &passports.schema,
+    )))
+  }
+
+  fn part_2(passports: &Passports) -> Result<Answer, AocError> {
+    Ok(Answer::Integer(count_valid(
+      &passports.records,
+      &passports.schema,
+    )))
+  }
+}
+
+// how many passports have every field the schema requires
+pub fn count_complete(records: &[HashMap<&str, &str>], schema: &Schema) -> u64 {
+  records
+    .iter()
+    .filter(|record| schema.is_complete(record))
+    .count() as u64
+}
+
+// how many passports pass every check in the schema
+pub fn count_valid(records: &[HashMap<&str, &str>], schema: &Schema) -> u64 {
+  records
+    .iter()
+    .filter(|record| schema.validate(record).is_empty())
+    .count() as u64
+}
+
+// the rules the puzzle gives, which Schema::default() uses
+const DEFAULT_SCHEMA: &str = include_str!("../../../data/2020/day-04-settings.toml");
+
+// what a passport has to have in it, and what each field has to look like; see
+// data/2020/day-04-settings.toml for the format
+#[derive(Debug)]
+pub struct Schema {
+  fields: Vec<FieldRule>,
+}
+
+#[derive(Debug)]
+struct FieldRule {
+  name: String,
+  required: bool,
+  checks: Vec<Check>,
+}
+
+// the checks on a value, made in this order, stopping at the first that fails
+#[derive(Debug)]
+enum Check {
+  // the pattern as the schema gives it, and the regex that matches all of a value against it
+  Pattern(String, Regex),
+  Range(RangeInclusive<i64>),
+  Units(Vec<(String, RangeInclusive<i64>)>),
+  OneOf(Vec<String>),
+}
+
+// why a field of a passport isn't valid
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+  Missing,
+  NoMatch {
+    value: String,
+    pattern: String,
+  },
+  NotANumber(String),
+  OutOfRange {
+    value: i64,
+    unit: String,
+    range: RangeInclusive<i64>,
+  },
+  NoUnit {
+    value: String,
+    units: Vec<String>,
+  },
+  NotOneOf {
+    value: String,
+    allowed: Vec<String>,
+  },
+}
+
+impl fmt::Display for Problem {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Problem::Missing => write!(f, "missing"),
+      Problem::NoMatch { value, pattern } => write!(f, "'{}' doesn't match {}", value, pattern),
+      Problem::NotANumber(value) => write!(f, "'{}' isn't a number", value),
+      Problem::OutOfRange { value, unit, range } => write!(
+        f,
+        "{}{} isn't between {}{} and {}{}",
+        value,
+        unit,
+        range.start(),
+        unit,
+        range.end(),
+        unit
+      ),
+      Problem::NoUnit { value, units } => write!(
+        f,
+        "'{}' isn't a number followed by one of {}",
+        value,
+        units.join(", ")
+      ),
+      Problem::NotOneOf { value, allowed } => {
+        write!(f, "'{}' isn't one of {}", value, allowed.join(", "))
+      }
+    }
+  }
+}
+
+// a field of a passport that isn't valid, and why
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+  pub field: String,
+  pub problem: Problem,
+}
+
+impl fmt::Display for FieldError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: {}", self.field, self.problem)
+  }
+}
+
+impl Default for Schema {
+  fn default() -> Schema {
+    Schema::from_toml(DEFAULT_SCHEMA).expect("the default schema should be valid")
+  }
+}
+
+impl Schema {
+  pub fn from_toml(text: &str) -> Result<Schema, String> {
+    let value = text.parse::<toml::Value>().map_err(|e| e.to_string())?;
+    let fields = match value.get("fields") {
+      Some(toml::Value::Array(fields)) => fields,
+      _ => return Err("expected [[fields]] tables".to_string()),
+    };
+
+    Ok(Schema {
+      fields: fields
+        .iter()
+        .map(FieldRule::from_toml)
+        .collect::<Result<_, _>>()?,
+    })
+  }
+
+  // whether the passport has every required field, whatever's in them
+  pub fn is_complete(&self, record: &HashMap<&str, &str>) -> bool {
+    self
+      .fields
+      .iter()
+      .all(|rule| !rule.required || record.contains_key(rule.name.as_str()))
+  }
+
+  // every field of the passport that isn't valid, in the schema's order; none if it's valid
+  pub fn validate(&self, record: &HashMap<&str, &str>) -> Vec<FieldError> {
+    self
+      .fields
+      .iter()
+      .filter_map(|rule| {
+        let problem = match record.get(rule.name.as_str()) {
+          Some(value) => rule.check(value).err()?,
+          None if rule.required => Problem::Missing,
+          None => return None,
+        };
+        Some(FieldError {
+          field: rule.name.clone(),
+          problem,
+        })
+      })
+      .collect()
+  }
+
+  // a single field, on its own; fields the schema doesn't mention are always valid
+  pub fn check(&self, field: &str, value: &str) -> Result<(), Problem> {
+    match self.fields.iter().find(|rule| rule.name == field) {
+      Some(rule) => rule.check(value),
+      None => Ok(()),
+    }
+  }
+}
+
+impl FieldRule {
+  fn from_toml(table: &toml::Value) -> Result<FieldRule, String> {
+    let name = table
+      .get("name")
+      .and_then(toml::Value::as_str)
+      .ok_or("every field needs a name")?
+      .to_string();
+    let error = |message: &str| format!("{}: {}", name, message);
+    let required = match table.get("required") {
+      Some(required) => required
+        .as_bool()
+        .ok_or_else(|| error("required should be true or false"))?,
+      None => true,
+    };
+    let mut checks = vec![];
+
+    if let Some(pattern) = table.get("pattern") {
+      let pattern = pattern
+        .as_str()
+        .ok_or_else(|| error("pattern should be a string"))?;
+      let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| error(&e.to_string()))?;
+      checks.push(Check::Pattern(pattern.to_string(), regex));
+    }
+    if let Some(range) = table.get("range") {
+      checks.push(Check::Range(
+        parse_range(range).ok_or_else(|| error("range should be [min, max]"))?,
+      ));
+    }
+    if let Some(units) = table.get("units") {
+      let units = units
+        .as_table()
+        .ok_or_else(|| error("units should be a table of unit = [min, max]"))?;
+      checks.push(Check::Units(
+        units
+          .iter()
+          .map(|(unit, range)| {
+            parse_range(range)
+              .map(|range| (unit.clone(), range))
+              .ok_or_else(|| error(&format!("units.{} should be [min, max]", unit)))
+          })
+          .collect::<Result<_, _>>()?,
+      ));
+    }
+    if let Some(allowed) = table.get("one_of") {
+      let allowed = allowed
+        .as_array()
+        .and_then(|allowed| {
+          allowed
+            .iter()
+            .map(|value| value.as_str().map(String::from))
+            .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| error("one_of should be a list of strings"))?;
+      checks.push(Check::OneOf(allowed));
+    }
+
+    Ok(FieldRule {
+      name,
+      required,
+      checks,
+    })
+  }
+
+  fn check(&self, value: &str) -> Result<(), Problem> {
+    self.checks.iter().try_for_each(|check| check.check(value))
+  }
+}
+
+impl Check {
+  fn check(&self, value: &str) -> Result<(), Problem> {
+    match self {
+      Check::Pattern(_, regex) if regex.is_match(value) => Ok(()),
+      Check::Pattern(pattern, _) => Err(Problem::NoMatch {
+        value: value.to_string(),
+        pattern: pattern.clone(),
+      }),
+      Check::Range(range) => {
+        let number = value
+          .parse::<i64>()
+          .map_err(|_| Problem::NotANumber(value.to_string()))?;
+        in_range(number, "", range)
+      }
+      Check::Units(units) => {
+        let (number, unit, range) = units
+          .iter()
+          .find_map(|(unit, range)| {
+            let number = value.strip_suffix(unit.as_str())?;
+            if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+              return None;
+            }
+            Some((number.parse::<i64>().ok()?, unit, range))
+          })
+          .ok_or_else(|| Problem::NoUnit {
+            value: value.to_string(),
+            units: units.iter().map(|(unit, _)| unit.clone()).collect(),
+          })?;
+        in_range(number, unit, range)
+      }
+      Check::OneOf(allowed) if allowed.iter().any(|allowed| allowed == value) => Ok(()),
+      Check::OneOf(allowed) => Err(Problem::NotOneOf {
+        value: value.to_string(),
+        allowed: allowed.clone(),
+      }),
+    }
+  }
+}
+
+fn in_range(value: i64, unit: &str, range: &RangeInclusive<i64>) -> Result<(), Problem> {
+  if range.contains(&value) {
+    Ok(())
+  } else {
+    Err(Problem::OutOfRange {
+      value,
+      unit: unit.to_string(),
+      range: range.clone(),
+    })
+  }
+}
+
+fn parse_range(value: &toml::Value) -> Option<RangeInclusive<i64>> {
+  match value.as_array()?.as_slice() {
+    [min, max] => Some(min.as_integer()?..=max.as_integer()?),
+    _ => None,
+  }
+}
+
+fn records<'a>(data: &'a [&'a str]) -> Result<Vec<HashMap<&'a str, &'a str>>, AocError> {
+  Input::new(data)
+    .paragraphs()
+    .map(|paragraph| record_as_hash_map(&paragraph))
+    .collect()
+}
+
+fn record_as_hash_map<'a>(paragraph: &Input<'a>) -> Result<HashMap<&'a str, &'a str>, AocError> {
+  let mut h = HashMap::new();
+  for (ix, line) in paragraph.lines().iter().enumerate() {
+    let mut column = 0;
+    for fav in line.split(' ') {
+      if !fav.is_empty() {
+        let (field, value) = split_field_and_value(fav).ok_or_else(|| {
+          paragraph
+            .error(ix, "expected field:value")
+            .at_column(column)
+        })?;
+        h.insert(field, value);
+      }
+      column += fav.len() + 1;
+    }
+  }
+  Ok(h)
+}
+
+fn split_field_and_value(fav: &str) -> Option<(&str, &str)> {
+  let colon = fav.find(':')?;
+  Some((&fav[..colon], &fav[colon + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use maplit::hashmap;
+
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "example" => include_str!("../../../data/2020/examples/day-04/example.txt"),
+      "invalid" => include_str!("../../../data/2020/examples/day-04/invalid.txt"),
+      _ => include_str!("../../../data/2020/examples/day-04/valid.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
+  fn valid(field: &str, value: &str) -> bool {
+    Schema::default().check(field, value).is_ok()
+  }
+
+  #[test]
+  fn test_part_1() {
+    assert_eq!(
+      Day04::run_part_1(&example("example")),
+      Ok(Answer::Integer(2))
+    );
+    assert_eq!(Day04::run_part_1(&[]), Ok(Answer::Integer(0)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    assert_eq!(
+      Day04::run_part_2(&example("invalid")),
+      Ok(Answer::Integer(0))
+    );
+    assert_eq!(Day04::run_part_2(&example("valid")), Ok(Answer::Integer(4)));
+  }
+
+  #[test]
+  fn test_is_complete() {
+    let record_1: HashMap<&str, &str> = hashmap! {
+      "ecl" => "gry",
+      "pid" => "860033327",
+      "eyr" => "2020",
+      "hcl" => "#fffffd",
+      "byr" => "1937",
+      "iyr" => "2017",
+      "cid" => "147",
+      "hgt" => "183cm",
+    };
+    assert!(Schema::default().is_complete(&record_1));
+
+    let record_2: HashMap<&str, &str> = hashmap! {
+      "iyr" => "2013",
+      "ecl" => "amb",
+      "cid" => "350",
+      "eyr" => "2023",
+      "pid" => "028048884",
+      "hcl" => "#cfa07d",
+      "byr" => "1929",
+    };
+    assert!(!Schema::default().is_complete(&record_2));
+
+    let record_3: HashMap<&str, &str> = hashmap! {
+      "hcl" => "#ae17e1",
+      "iyr" => "2013",
+      "eyr" => "2024",
+      "ecl" => "brn",
+      "pid" => "760753108",
+      "byr" => "1931",
+      "hgt" => "179cm",
+    };
+    assert!(Schema::default().is_complete(&record_3));
+
+    let record_4: HashMap<&str, &str> = hashmap! {
+      "hcl" => "#cfa07d",
+      "eyr" => "2025",
+      "pid" => "166559648",
+      "iyr" => "2011",
+      "ecl" => "brn",
+      "hgt" => "59in",
+    };
+    assert!(!Schema::default().is_complete(&record_4));
+  }
+
+  #[test]
+  fn test_validate_record() {
+    let invalid_1: HashMap<&str, &str> = hashmap! {
+      "eyr" => "1972",
+      "cid" => "100",
+      "hcl" => "#18171d",
+      "ecl" => "amb",
+      "hgt" => "170",
+      "pid" => "186cm",
+      "iyr" => "2018",
+      "byr" => "1926",
+    };
+    assert!(!Schema::default().validate(&invalid_1).is_empty());
+
+    let valid_1: HashMap<&str, &str> = hashmap! {
+      "pid" => "087499704",
+      "hgt" => "74in",
+      "ecl" => "grn",
+      "iyr" => "2012",
+      "eyr" => "2030",
+      "byr" => "1980",
+      "hcl" => "#623a2f",
+    };
+    assert!(Schema::default().validate(&valid_1).is_empty());
+  }
+
+  #[test]
+  fn test_byr() {
+    assert!(valid("byr", "2002"));
+    assert!(!valid("byr", "2003"));
+  }
+
+  #[test]
+  fn test_iyr() {
+    assert!(valid("iyr", "2020"));
+    assert!(!valid("iyr", "2021"));
+  }
+
+  #[test]
+  fn test_eyr() {
+    assert!(valid("eyr", "2030"));
+    assert!(!valid("eyr", "2031"));
+  }
+
+  #[test]
+  fn test_hgt() {
+    assert!(valid("hgt", "60in"));
+    assert!(valid("hgt", "190cm"));
+    assert!(!valid("hgt", "190in"));
+    assert!(!valid("hgt", "190"));
+  }
+
+  #[test]
+  fn test_hcl() {
+    assert!(valid("hcl", "#123abc"));
+    assert!(!valid("hcl", "#123abz"));
+    assert!(!valid("hcl", "123abc"));
+  }
+
+  #[test]
+  fn test_ecl() {
+    assert!(valid("ecl", "brn"));
+    assert!(!valid("ecl", "wat"));
+  }
+
+  #[test]
+  fn test_pid() {
+    assert!(valid("pid", "000000001"));
+    assert!(!valid("pid", "0123456789"));
+  }
+
+  #[test]
+  fn test_validate() {
+    let record: HashMap<&str, &str> = hashmap! {
+      "eyr" => "1972",
+      "cid" => "100",
+      "hcl" => "#18171d",
+      "ecl" => "amb",
+      "hgt" => "170",
+      "pid" => "186cm",
+      "byr" => "1926",
+    };
+    assert_eq!(
+      Schema::default()
+        .validate(&record)
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>(),
+      vec![
+        "iyr: missing",
+        "eyr: 1972 isn't between 2020 and 2030",
+        "hgt: '170' isn't a number followed by one of cm, in",
+        "pid: '186cm' doesn't match \\d{9}",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_from_toml() {
+    let schema = Schema::from_toml(
+      r#"
+      [[fields]]
+      name = "hgt"
+      required = false
+      units = { cm = [150, 193] }
+
+      [[fields]]
+      name = "ecl"
+      one_of = ["amb"]
+      "#,
+    )
+    .unwrap();
+
+    assert!(schema.is_complete(&hashmap! { "ecl" => "wat" }));
+    assert_eq!(
+      schema.validate(&hashmap! { "ecl" => "wat" }),
+      vec![FieldError {
+        field: "ecl".to_string(),
+        problem: Problem::NotOneOf {
+          value: "wat".to_string(),
+          allowed: vec!["amb".to_string()]
+        }
+      }]
+    );
+    assert_eq!(
+      schema.check("hgt", "60in"),
+      Err(Problem::NoUnit {
+        value: "60in".to_string(),
+        units: vec!["cm".to_string()]
+      })
+    );
+    assert_eq!(
+      schema.check("hgt", "194cm"),
+      Err(Problem::OutOfRange {
+        value: 194,
+        unit: "cm".to_string(),
+        range: 150..=193
+      })
+    );
+    assert_eq!(schema.check("byr", "whenever"), Ok(()));
+
+    // a pattern has to match all of the value
+    let schema = Schema::from_toml("[[fields]]\nname = 'byr'\npattern = '\\d{4}|x'").unwrap();
+    assert_eq!(schema.check("byr", "1937"), Ok(()));
+    assert!(schema.check("byr", "19370").is_err());
+    assert!(schema.check("byr", "1937x").is_err());
+    assert_eq!(
+      count_valid(&records(&example("example")).unwrap(), &schema),
+      3
+    );
+
+    assert!(Schema::from_toml("[[fields]]\nrequired = true").is_err());
+    assert!(Schema::from_toml("[[fields]]\nname = 'byr'\nrange = [1920]").is_err());
+    assert!(Schema::from_toml("[[fields]]\nname = 'byr'\npattern = '('").is_err());
+
+    // the settings the runner passes in replace the default rules
+    let data = example("example");
+    let settings = "[[fields]]\nname = 'byr'\nrequired = true";
+    let passports = Day04::parse(&Input::new(&data).with_settings(Some(settings))).unwrap();
+    assert_eq!(Day04::part_1(&passports), Ok(Answer::Integer(3)));
+    assert!(matches!(
+      Day04::parse(&Input::new(&data).with_settings(Some("fields = 1"))),
+      Err(AocError::Settings(_))
+    ));
+  }
+
+  #[test]
+  fn test_records() {
+    let data = example("example");
+
+    let actual = records(&data).unwrap();
+    let expected = hashmap! {
+      "ecl" => "gry",
+      "pid" => "860033327",
+      "eyr" => "2020",
+      "hcl" => "#fffffd",
+      "byr" => "1937",
+      "iyr" => "2017",
+      "cid" => "147",
+      "hgt" => "183cm",
+    };
+
+    assert_eq!(actual.len(), 4);
+    assert_eq!(actual[0], expected);
+
+    assert_eq!(
+      Day04::run_part_1(&["ecl:gry pid:860033327", "", "eyr:2020 hcl #fffffd"]),
+      Err(AocError::parse(2, "eyr:2020 hcl #fffffd", "expected field:value").at_column(9))
+    );
+  }
+}
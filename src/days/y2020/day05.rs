@@ -0,0 +1,428 @@
+use crate::{Answer, AocError};
+use std::collections::HashSet;
+use std::fmt;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  parse_passes(Plane::DEFAULT, data)?
+    .iter()
+    .map(BoardingPass::id)
+    .max()
+    .map(Answer::from)
+    .ok_or_else(|| AocError::no_solution("there are no boarding passes"))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let occupied: HashSet<u32> = parse_passes(Plane::DEFAULT, data)?
+    .iter()
+    .map(BoardingPass::id)
+    .collect();
+
+  let gap = gaps(Plane::DEFAULT, &occupied).next();
+  gap
+    .map(Answer::from)
+    .ok_or_else(|| AocError::no_solution("there is no empty seat between two occupied ones"))
+}
+
+// how many letters of a pass pick the row (F for the front half, B for the back) and how many
+// then pick the column (L for the left half, R for the right)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Plane {
+  row_bits: u32,
+  column_bits: u32,
+}
+
+impl Plane {
+  // the puzzle's plane, with 128 rows of 8 seats
+  pub const DEFAULT: Plane = Plane {
+    row_bits: 7,
+    column_bits: 3,
+  };
+
+  // seat IDs are u32s, so there can't be more than 2^32 seats
+  const MAX_BITS: u32 = 32;
+
+  pub fn new(row_bits: u32, column_bits: u32) -> Result<Plane, PassError> {
+    let bits = row_bits.checked_add(column_bits);
+    if bits.is_none_or(|bits| bits > Plane::MAX_BITS) {
+      return Err(PassError::TooManySeats {
+        row_bits,
+        column_bits,
+      });
+    }
+    Ok(Plane {
+      row_bits,
+      column_bits,
+    })
+  }
+
+  pub fn rows(&self) -> u64 {
+    1 << self.row_bits
+  }
+
+  pub fn columns(&self) -> u64 {
+    1 << self.column_bits
+  }
+
+  pub fn seats(&self) -> u64 {
+    self.rows() * self.columns()
+  }
+
+  fn letters(&self) -> usize {
+    (self.row_bits + self.column_bits) as usize
+  }
+
+  // which of a pair of letters means 0 and 1 at position ix of a pass
+  fn letters_at(&self, ix: usize) -> (char, char) {
+    if ix < self.row_bits as usize {
+      ('F', 'B')
+    } else {
+      ('L', 'R')
+    }
+  }
+}
+
+impl Default for Plane {
+  fn default() -> Plane {
+    Plane::DEFAULT
+  }
+}
+
+// why a boarding pass doesn't make sense for a plane
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PassError {
+  WrongLength {
+    expected: usize,
+    found: usize,
+  },
+  // ix is the 0-based byte offset of the letter, so that it can go to AocError::at_column()
+  WrongLetter {
+    ix: usize,
+    found: char,
+    expected: (char, char),
+  },
+  NoSuchSeat {
+    id: u64,
+    seats: u64,
+  },
+  TooManySeats {
+    row_bits: u32,
+    column_bits: u32,
+  },
+}
+
+impl fmt::Display for PassError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PassError::WrongLength { expected, found } => {
+        write!(f, "expected {} letters, found {}", expected, found)
+      }
+      PassError::WrongLetter {
+        found, expected, ..
+      } => write!(
+        f,
+        "expected '{}' or '{}', found '{}'",
+        expected.0, expected.1, found
+      ),
+      PassError::NoSuchSeat { id, seats } => {
+        write!(f, "there's no seat {} on a plane with {} seats", id, seats)
+      }
+      PassError::TooManySeats {
+        row_bits,
+        column_bits,
+      } => write!(
+        f,
+        "a plane with {} row bits and {} column bits has too many seats",
+        row_bits, column_bits
+      ),
+    }
+  }
+}
+
+// a seat on a plane; the letters of its pass are the bits of its ID, which is
+// row * plane.columns() + column
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoardingPass {
+  plane: Plane,
+  id: u32,
+}
+
+impl BoardingPass {
+  pub fn decode(plane: Plane, pass: &str) -> Result<BoardingPass, PassError> {
+    let found = pass.chars().count();
+    if found != plane.letters() {
+      return Err(PassError::WrongLength {
+        expected: plane.letters(),
+        found,
+      });
+    }
+
+    let mut id = 0;
+    for (bit, (ix, c)) in pass.char_indices().enumerate() {
+      let (zero, one) = plane.letters_at(bit);
+      id = match c {
+        _ if c == zero => id << 1,
+        _ if c == one => id << 1 | 1,
+        _ => {
+          return Err(PassError::WrongLetter {
+            ix,
+            found: c,
+            expected: (zero, one),
+          })
+        }
+      };
+    }
+
+    Ok(BoardingPass { plane, id })
+  }
+
+  pub fn from_id(plane: Plane, id: u64) -> Result<BoardingPass, PassError> {
+    if id >= plane.seats() {
+      return Err(PassError::NoSuchSeat {
+        id,
+        seats: plane.seats(),
+      });
+    }
+    Ok(BoardingPass {
+      plane,
+      id: id as u32,
+    })
+  }
+
+  pub fn encode(&self) -> String {
+    (0..self.plane.letters())
+      .map(|bit| {
+        let (zero, one) = self.plane.letters_at(bit);
+        if self.id >> (self.plane.letters() - 1 - bit) & 1 == 0 {
+          zero
+        } else {
+          one
+        }
+      })
+      .collect()
+  }
+
+  pub fn id(&self) -> u32 {
+    self.id
+  }
+
+  pub fn row(&self) -> u32 {
+    (u64::from(self.id) >> self.plane.column_bits) as u32
+  }
+
+  pub fn column(&self) -> u32 {
+    (u64::from(self.id) % self.plane.columns()) as u32
+  }
+}
+
+impl fmt::Display for BoardingPass {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.encode())
+  }
+}
+
+fn parse_passes(plane: Plane, data: &[&str]) -> Result<Vec<BoardingPass>, AocError> {
+  data
+    .iter()
+    .enumerate()
+    .map(|(ix, pass)| {
+      BoardingPass::decode(plane, pass).map_err(|e| match e {
+        PassError::WrongLetter { ix: column, .. } => {
+          AocError::parse(ix, pass, e.to_string()).at_column(column)
+        }
+        _ => AocError::parse(ix, pass, e.to_string()),
+      })
+    })
+    .collect()
+}
+
+// the empty seats with occupied seats on either side of them, in order of ID
+fn gaps(plane: Plane, occupied: &HashSet<u32>) -> impl Iterator<Item = u32> + '_ {
+  (1..plane.seats().saturating_sub(1))
+    .map(|id| id as u32)
+    .filter(move |id| {
+      !occupied.contains(id) && occupied.contains(&(id - 1)) && occupied.contains(&(id + 1))
+    })
+}
+
+// a row of the plane on each line, front first, with its number and then a '#' for each
+// occupied seat, an 'o' for each empty seat with occupied seats on either side of it (what
+// part_2 looks for) and a '.' for every other empty seat:
+//
+//   0 ........
+//   1 ###o####
+pub fn seat_map(plane: Plane, passes: &[BoardingPass]) -> String {
+  let occupied: HashSet<u32> = passes.iter().map(BoardingPass::id).collect();
+  let gaps: HashSet<u32> = gaps(plane, &occupied).collect();
+  let width = (plane.rows() - 1).to_string().len();
+  let mut map = String::new();
+
+  for row in 0..plane.rows() {
+    map += &format!("{:>width$} ", row, width = width);
+    for column in 0..plane.columns() {
+      let id = (row * plane.columns() + column) as u32;
+      map.push(if occupied.contains(&id) {
+        '#'
+      } else if gaps.contains(&id) {
+        'o'
+      } else {
+        '.'
+      });
+    }
+    map.push('\n');
+  }
+
+  map
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-05/example.txt")
+      .lines()
+      .collect()
+  }
+
+  fn decode(pass: &str) -> Result<BoardingPass, PassError> {
+    BoardingPass::decode(Plane::DEFAULT, pass)
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = get_test_data();
+
+    assert_eq!(part_1(&data), Ok(Answer::Integer(820)));
+    assert_eq!(
+      part_1(&["FBFBBFFRLR", "FBFBBFFRLX"]),
+      Err(AocError::parse(1, "FBFBBFFRLX", "expected 'L' or 'R', found 'X'").at_column(9))
+    );
+    assert_eq!(
+      part_1(&["BBFFBBFRL"]),
+      Err(AocError::parse(
+        0,
+        "BBFFBBFRL",
+        "expected 10 letters, found 9"
+      ))
+    );
+  }
+
+  #[test]
+  fn test_part_2() {
+    assert_eq!(
+      part_2(&["FFFFFFBLLR", "FFFFFFBLRR", "FFFFFFBLLL"]),
+      Ok(Answer::Integer(10))
+    );
+    assert!(part_2(&["FFFFFFBLLR", "FFFFFFBLRL"]).is_err());
+  }
+
+  #[test]
+  fn test_decode() {
+    let pass = decode("FBFBBFFRLR").unwrap();
+    assert_eq!((pass.row(), pass.column(), pass.id()), (44, 5, 357));
+
+    for (pass, row, column, id) in &[
+      ("BFFFBBFRRR", 70, 7, 567),
+      ("FFFBBBFRRR", 14, 7, 119),
+      ("BBFFBBFRLL", 102, 4, 820),
+    ] {
+      let pass = decode(pass).unwrap();
+      assert_eq!((pass.row(), pass.column(), pass.id()), (*row, *column, *id));
+    }
+  }
+
+  #[test]
+  fn test_decode_errors() {
+    assert_eq!(
+      decode("BBFFBBFRL"),
+      Err(PassError::WrongLength {
+        expected: 10,
+        found: 9
+      })
+    );
+    assert_eq!(
+      decode("BBFFBBFRLX"),
+      Err(PassError::WrongLetter {
+        ix: 9,
+        found: 'X',
+        expected: ('L', 'R')
+      })
+    );
+    // rows come before columns
+    assert_eq!(
+      decode("BBFFBBRFLL"),
+      Err(PassError::WrongLetter {
+        ix: 6,
+        found: 'R',
+        expected: ('F', 'B')
+      })
+    );
+    assert_eq!(
+      decode("BBFFBBFRLé").unwrap_err().to_string(),
+      "expected 'L' or 'R', found 'é'"
+    );
+  }
+
+  #[test]
+  fn test_encode() {
+    assert_eq!(
+      BoardingPass::from_id(Plane::DEFAULT, 357).map(|pass| pass.encode()),
+      Ok("FBFBBFFRLR".to_string())
+    );
+    assert_eq!(
+      BoardingPass::from_id(Plane::DEFAULT, 1024),
+      Err(PassError::NoSuchSeat {
+        id: 1024,
+        seats: 1024
+      })
+    );
+    for id in 0..Plane::DEFAULT.seats() {
+      let pass = BoardingPass::from_id(Plane::DEFAULT, id).unwrap();
+      assert_eq!(decode(&pass.to_string()), Ok(pass));
+    }
+  }
+
+  #[test]
+  fn test_plane() {
+    let plane = Plane::new(2, 1).unwrap();
+    assert_eq!((plane.rows(), plane.columns(), plane.seats()), (4, 2, 8));
+
+    let pass = BoardingPass::decode(plane, "BFR").unwrap();
+    assert_eq!((pass.row(), pass.column(), pass.id()), (2, 1, 5));
+    assert_eq!(BoardingPass::from_id(plane, 5).unwrap().encode(), "BFR");
+    assert!(BoardingPass::decode(plane, "FBFBBFFRLR").is_err());
+
+    assert_eq!(Plane::new(0, 0).map(|plane| plane.seats()), Ok(1));
+    assert_eq!(Plane::new(20, 12).map(|plane| plane.seats()), Ok(1 << 32));
+    assert_eq!(
+      Plane::new(20, 13),
+      Err(PassError::TooManySeats {
+        row_bits: 20,
+        column_bits: 13
+      })
+    );
+    assert!(Plane::new(u32::MAX, 1).is_err());
+    assert_eq!(
+      Plane::new(1, u32::MAX).unwrap_err().to_string(),
+      "a plane with 1 row bits and 4294967295 column bits has too many seats"
+    );
+  }
+
+  #[test]
+  fn test_seat_map() {
+    let plane = Plane::new(2, 2).unwrap();
+    let passes: Vec<BoardingPass> = ["FBLL", "FBLR", "FBRR", "BFLL", "BBLR"]
+      .iter()
+      .map(|pass| BoardingPass::decode(plane, pass).unwrap())
+      .collect();
+
+    assert_eq!(
+      seat_map(plane, &passes),
+      "0 ....
+1 ##o#
+2 #...
+3 .#..
+"
+    );
+  }
+}
@@ -0,0 +1,80 @@
+use crate::{Answer, AocError, Input};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  Ok(Answer::Integer(
+    Input::new(data)
+      .paragraphs()
+      .map(|group| count_any_answers(group.lines()))
+      .sum::<u64>(),
+  ))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  Ok(Answer::Integer(
+    Input::new(data)
+      .paragraphs()
+      .map(|group| count_all_answers(group.lines()))
+      .sum::<u64>(),
+  ))
+}
+
+fn count_all_answers(group: &[&str]) -> u64 {
+  let mut iter = group.iter();
+  let mut first_group: HashSet<char> = HashSet::from_iter(iter.next().unwrap().trim().chars());
+  for next_str in iter {
+    let next_group: HashSet<char> = HashSet::from_iter(next_str.trim().chars());
+    first_group = first_group.intersection(&next_group).copied().collect();
+  }
+
+  first_group.len() as u64
+}
+
+fn count_any_answers(group: &[&str]) -> u64 {
+  let mut answers: HashSet<char> = HashSet::new();
+  for c in group.iter().flat_map(|person| person.trim().chars()) {
+    answers.insert(c);
+  }
+  answers.len() as u64
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-06/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(11)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(6)));
+  }
+
+  #[test]
+  fn test_count_all_answers() {
+    assert_eq!(count_all_answers(&["abc"]), 3);
+    assert_eq!(count_all_answers(&["a", "b", "c"]), 0);
+    assert_eq!(count_all_answers(&["ab", "ac"]), 1);
+    assert_eq!(count_all_answers(&["a", "a", "a", "a"]), 1);
+    assert_eq!(count_all_answers(&["b"]), 1);
+  }
+
+  #[test]
+  fn test_count_any_answers() {
+    assert_eq!(count_any_answers(&["abc"]), 3);
+    assert_eq!(count_any_answers(&["ab", "ac"]), 3);
+    assert_eq!(count_any_answers(&["a", "a", "a", "a"]), 1);
+    assert_eq!(count_any_answers(&["b"]), 1);
+  }
+}
@@ -0,0 +1,295 @@
+use crate::{Answer, AocError};
+use petgraph::graphmap::GraphMap;
+use petgraph::prelude::*;
+use petgraph::visit::Walker;
+use regex::Regex;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let graph = build_graph(data, false)?;
+
+  if !graph.contains_node("shiny gold") {
+    return Err(AocError::no_solution("no rule mentions 'shiny gold'"));
+  }
+
+  // every bag reachable from "shiny gold" can contain it; don't count "shiny gold" itself
+  Ok(Answer::from(
+    Bfs::new(&graph, "shiny gold").iter(&graph).count() - 1,
+  ))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let graph = build_graph(data, true)?;
+
+  if !graph.contains_node("shiny gold") {
+    return Err(AocError::no_solution("no rule mentions 'shiny gold'"));
+  }
+
+  // we have to subtract one here because count_contents() counts the outer bag as well
+  Ok(Answer::from(count_contents(&graph, "shiny gold") - 1))
+}
+
+// a bag, with how many of each other bag it contains
+type Dependencies<'a> = (&'a str, Vec<(u32, &'a str)>);
+
+// ix is the rule's index in the input, for error messages
+fn build_dependencies(ix: usize, rule: &str) -> Result<Dependencies<'_>, AocError> {
+  let parts = split_rule(rule);
+  let (outer, contents) = match parts.as_slice() {
+    [outer, contents] => (*outer, *contents),
+    _ => {
+      return Err(AocError::parse(
+        ix,
+        rule,
+        "expected a rule like 'x bags contain 1 y bag.'",
+      ))
+    }
+  };
+  let inner = split_contents(contents)
+    .iter()
+    .map(|bag| extract_bag_descriptor(ix, rule, bag))
+    .collect::<Result<_, _>>()?;
+  Ok((outer, inner))
+}
+
+fn build_graph<'a>(
+  data: &'a [&'a str],
+  top_down: bool,
+) -> Result<GraphMap<&'a str, u64, Directed>, AocError> {
+  let mut graph = GraphMap::<&str, u64, Directed>::new();
+  let dependencies: Vec<Dependencies> = data
+    .iter()
+    .enumerate()
+    .map(|(ix, rule)| build_dependencies(ix, rule))
+    .collect::<Result<_, _>>()?;
+  for (bag, contents) in dependencies {
+    graph.add_node(bag);
+    for (quantity, descriptor) in contents {
+      graph.add_node(descriptor);
+      if top_down {
+        graph.add_edge(bag, descriptor, quantity.into());
+      } else {
+        graph.add_edge(descriptor, bag, quantity.into());
+      }
+    }
+  }
+
+  Ok(graph)
+}
+
+fn count_contents(graph: &GraphMap<&str, u64, Directed>, outer: &str) -> u64 {
+  graph
+    .neighbors_directed(outer, Outgoing)
+    .map(|inner| count_contents(graph, inner) * *graph.edge_weight(outer, inner).unwrap_or(&0))
+    .sum::<u64>()
+    + 1
+}
+
+// bag is one of the items in rule's contents, like "2 muted yellow bags."
+fn extract_bag_descriptor<'a>(
+  ix: usize,
+  rule: &str,
+  bag: &'a str,
+) -> Result<(u32, &'a str), AocError> {
+  let column = bag.as_ptr() as usize - rule.as_ptr() as usize;
+  let error = |message: String| AocError::parse(ix, rule, message).at_column(column);
+
+  let regex = Regex::new(r"^(\d+)\s+(.+)\s+bags?\.?$").unwrap();
+  let captures = regex.captures(bag).ok_or_else(|| {
+    error(format!(
+      "expected a bag like '1 shiny gold bag', found '{}'",
+      bag
+    ))
+  })?;
+  let quantity = captures
+    .get(1)
+    .unwrap()
+    .as_str()
+    .parse::<u32>()
+    .map_err(|e| error(e.to_string()))?;
+  let descriptor: &str = captures.get(2).unwrap().as_str();
+  Ok((quantity, descriptor))
+}
+
+fn split_contents(contents: &str) -> Vec<&str> {
+  if contents.trim_end_matches('.') == "no other bags" {
+    vec![]
+  } else {
+    contents.split(", ").map(str::trim).collect()
+  }
+}
+
+fn split_rule(rule: &str) -> Vec<&str> {
+  rule.splitn(2, " bags contain ").collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "example" => include_str!("../../../data/2020/examples/day-07/example.txt"),
+      _ => include_str!("../../../data/2020/examples/day-07/nested.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = example("example");
+    assert_eq!(part_1(&data), Ok(Answer::Integer(4)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = example("example");
+    assert_eq!(part_2(&data), Ok(Answer::Integer(32)));
+  }
+
+  #[test]
+  fn test_build_dependencies() {
+    assert_eq!(
+      build_dependencies(
+        0,
+        "light red bags contain 1 bright white bag, 2 muted yellow bags."
+      ),
+      Ok(("light red", vec![(1, "bright white"), (2, "muted yellow")]))
+    );
+    assert_eq!(
+      build_dependencies(0, "light red bags contain 300 shiny gold bags."),
+      Ok(("light red", vec![(300, "shiny gold")]))
+    );
+    assert_eq!(
+      build_dependencies(0, "faded blue bags contain no other bags."),
+      Ok(("faded blue", vec![]))
+    );
+    assert_eq!(
+      build_dependencies(2, "light red bags"),
+      Err(AocError::parse(
+        2,
+        "light red bags",
+        "expected a rule like 'x bags contain 1 y bag.'"
+      ))
+    );
+
+    let rule = "light red bags contain 1 bright white bag, lots of shiny gold bags.";
+    assert_eq!(
+      build_dependencies(0, rule),
+      Err(
+        AocError::parse(
+          0,
+          rule,
+          "expected a bag like '1 shiny gold bag', found 'lots of shiny gold bags.'"
+        )
+        .at_column(43)
+      )
+    );
+    let rule = "light red bags contain 99999999999 shiny gold bags.";
+    assert_eq!(
+      build_dependencies(0, rule),
+      Err(AocError::parse(0, rule, "number too large to fit in target type").at_column(23))
+    );
+  }
+
+  #[test]
+  fn test_shiny_gold_missing() {
+    let data = ["faded blue bags contain no other bags."];
+    let error = Err(AocError::no_solution("no rule mentions 'shiny gold'"));
+    assert_eq!(part_1(&data), error);
+    assert_eq!(part_2(&data), error);
+  }
+
+  #[test]
+  fn test_build_graph() {
+    let data = example("example");
+    let top_down = build_graph(&data, true).unwrap();
+    assert!(top_down.contains_edge("bright white", "shiny gold"));
+    assert!(top_down.contains_edge("muted yellow", "shiny gold"));
+    assert!(top_down.contains_edge("dark orange", "bright white"));
+    assert!(top_down.contains_edge("dark orange", "muted yellow"));
+
+    let bottom_up = build_graph(&data, false).unwrap();
+    assert!(bottom_up.contains_edge("shiny gold", "bright white"));
+    assert!(bottom_up.contains_edge("shiny gold", "muted yellow"));
+    assert!(bottom_up.contains_edge("bright white", "dark orange"));
+    assert!(bottom_up.contains_edge("muted yellow", "dark orange"));
+  }
+
+  #[test]
+  fn test_count_contents() {
+    {
+      let data = example("example");
+      let graph = build_graph(&data, true).unwrap();
+      assert_eq!(count_contents(&graph, "faded blue"), 1);
+      assert_eq!(count_contents(&graph, "dotted black"), 1);
+      assert_eq!(count_contents(&graph, "vibrant plum"), 12);
+      assert_eq!(count_contents(&graph, "shiny gold"), 33)
+    }
+    {
+      let data = example("nested");
+      let graph = build_graph(&data, true).unwrap();
+      assert_eq!(count_contents(&graph, "shiny gold"), 127)
+    }
+  }
+
+  #[test]
+  fn test_extract_bag_descriptor() {
+    assert_eq!(
+      extract_bag_descriptor(0, "1 bright white bag", "1 bright white bag").unwrap(),
+      (1, "bright white")
+    );
+    assert_eq!(
+      extract_bag_descriptor(0, "2 muted yellow bags.", "2 muted yellow bags.").unwrap(),
+      (2, "muted yellow")
+    );
+  }
+
+  #[test]
+  fn test_split_contents() {
+    assert_eq!(
+      split_contents("1 bright white bag, 2 muted yellow bags."),
+      vec!["1 bright white bag", "2 muted yellow bags."]
+    );
+  }
+
+  #[test]
+  fn test_split_rule() {
+    assert_eq!(
+      split_rule("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+      vec!["light red", "1 bright white bag, 2 muted yellow bags."]
+    );
+    assert_eq!(
+      split_rule("dark orange bags contain 3 bright white bags, 4 muted yellow bags."),
+      vec!["dark orange", "3 bright white bags, 4 muted yellow bags."]
+    );
+    assert_eq!(
+      split_rule("bright white bags contain 1 shiny gold bag."),
+      vec!["bright white", "1 shiny gold bag."]
+    );
+    assert_eq!(
+      split_rule("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags."),
+      vec!["muted yellow", "2 shiny gold bags, 9 faded blue bags."]
+    );
+    assert_eq!(
+      split_rule("shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags."),
+      vec!["shiny gold", "1 dark olive bag, 2 vibrant plum bags."]
+    );
+    assert_eq!(
+      split_rule("dark olive bags contain 3 faded blue bags, 4 dotted black bags."),
+      vec!["dark olive", "3 faded blue bags, 4 dotted black bags."]
+    );
+    assert_eq!(
+      split_rule("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
+      vec!["vibrant plum", "5 faded blue bags, 6 dotted black bags."]
+    );
+    assert_eq!(
+      split_rule("faded blue bags contain no other bags."),
+      vec!["faded blue", "no other bags."]
+    );
+    assert_eq!(
+      split_rule("dotted black bags contain no other bags."),
+      vec!["dotted black", "no other bags."]
+    );
+  }
+}
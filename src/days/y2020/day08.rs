@@ -1,4 +1,4 @@
-use common::{Day, Part};
+use crate::{Answer, AocError};
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -22,18 +22,13 @@ const JMP: &str = "jmp";
 const NOP: &str = "nop";
 
 impl<'a> Device<'a> {
-  pub fn from_slice(image: &'a [&'a str]) -> Device<'a> {
+  pub fn from_slice(image: &'a [&'a str]) -> Result<Device<'a>, AocError> {
     let parsed_image = image
       .iter()
-      .map(|line| {
-        // println!("parsing {}", line);
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        let operation = parts[0];
-        let argument = Argument::from_str(parts[1]).unwrap();
-        (operation, argument)
-      })
-      .collect();
-    Device {
+      .enumerate()
+      .map(|(ix, line)| parse_instruction(ix, line))
+      .collect::<Result<_, _>>()?;
+    Ok(Device {
       ram: parsed_image,
       pc: 0,
       acc: 0,
@@ -41,10 +36,10 @@ impl<'a> Device<'a> {
       instructions_executed: 0,
       instructions_limit: None,
       trace_enabled: false,
-    }
+    })
   }
 
-  pub fn peek(&self, addr: usize) -> Instruction {
+  pub fn peek(&self, addr: usize) -> Instruction<'_> {
     if let Some(value) = self.ram.get(addr) {
       *value
     } else {
@@ -68,7 +63,7 @@ impl<'a> Device<'a> {
     }
   }
 
-  pub fn run(&mut self) -> (Option<Operation>, Argument) {
+  pub fn run(&mut self) -> (Option<Operation<'_>>, Argument) {
     let mut last_operation = None;
 
     loop {
@@ -111,7 +106,7 @@ impl<'a> Device<'a> {
     (last_operation, self.acc)
   }
 
-  fn fetch_instruction(&self) -> Instruction {
+  fn fetch_instruction(&self) -> Instruction<'_> {
     if self.pc >= self.ram.len() {
       (HLT, 0)
     } else {
@@ -147,41 +142,20 @@ impl<'a> Device<'a> {
   }
 }
 
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-08-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(1675, day.part_1.result);
-    assert_eq!(1532, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-08-input.txt");
-    std::process::exit(1);
-  }
-}
-
-pub fn part_1(data: &[&str]) -> u64 {
-  let mut device = Device::from_slice(data);
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let mut device = Device::from_slice(data)?;
   if let (None, argument) = device.run() {
-    argument as u64
+    Ok(Answer::from(argument))
   } else {
-    panic!("device did not return a value");
+    Err(AocError::no_solution("program halted instead of looping"))
   }
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
   let mut retval = None;
 
   for (ix, _) in data.iter().enumerate() {
-    let mut device = Device::from_slice(data);
+    let mut device = Device::from_slice(data)?;
 
     match device.peek(ix) {
       (JMP, argument) => device.poke(ix, (NOP, argument)),
@@ -196,29 +170,72 @@ pub fn part_2(data: &[&str]) -> u64 {
   }
 
   if let Some(r) = retval {
-    r as u64
+    Ok(Answer::from(r))
   } else {
-    panic!("device did not return a value");
+    Err(AocError::no_solution(
+      "no single jmp/nop swap makes the program halt",
+    ))
   }
 }
 
+fn parse_instruction<'a>(ix: usize, line: &'a str) -> Result<Instruction<'a>, AocError> {
+  let parts: Vec<&str> = line.split_whitespace().collect();
+  let column_of = |part: &str| part.as_ptr() as usize - line.as_ptr() as usize;
+
+  let operation = match parts.first() {
+    Some(&operation) if [ACC, HLT, JMP, NOP].contains(&operation) => operation,
+    Some(&operation) => {
+      return Err(
+        AocError::parse(ix, line, format!("unknown operation: {}", operation))
+          .at_column(column_of(operation)),
+      )
+    }
+    None => return Err(AocError::parse(ix, line, "expected an instruction")),
+  };
+
+  let argument = match parts.get(1) {
+    Some(argument) => Argument::from_str(argument)
+      .map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(column_of(argument)))?,
+    None => {
+      return Err(AocError::parse(ix, line, "expected an argument").at_column(line.len()));
+    }
+  };
+
+  Ok((operation, argument))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-08/example.txt")
+      .lines()
+      .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec![
-      "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4", "acc +6",
-    ];
-    assert_eq!(part_1(&data), 5);
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Signed(5)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec![
-      "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4", "acc +6",
-    ];
-    assert_eq!(part_2(&data), 8);
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Signed(8)));
+  }
+
+  #[test]
+  fn test_parse_instruction() {
+    assert_eq!(parse_instruction(0, "jmp -4"), Ok((JMP, -4)));
+    assert_eq!(
+      parse_instruction(3, "jmp four"),
+      Err(AocError::parse(3, "jmp four", "invalid digit found in string").at_column(4))
+    );
+    assert_eq!(
+      parse_instruction(3, "mul +2"),
+      Err(AocError::parse(3, "mul +2", "unknown operation: mul").at_column(0))
+    );
   }
 }
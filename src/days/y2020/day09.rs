@@ -0,0 +1,187 @@
+use crate::{Answer, AocError};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  find_first_step(data, preamble_size(data)).map(Answer::from)
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let invalid_number = find_first_step(data, preamble_size(data))?;
+  find_weakness(data, invalid_number).map(Answer::from)
+}
+
+// the puzzle's preamble is 25 numbers, but its example is too short for that, and uses 5
+fn preamble_size(data: &[&str]) -> usize {
+  if data.len() > 25 {
+    25
+  } else {
+    5
+  }
+}
+
+fn parse(data: &[&str]) -> Result<Vec<u64>, AocError> {
+  data
+    .iter()
+    .enumerate()
+    .map(|(ix, line)| {
+      line
+        .parse()
+        .map_err(|e: std::num::ParseIntError| AocError::parse(ix, line, e.to_string()))
+    })
+    .collect()
+}
+
+fn initialize(
+  numbers: &[u64],
+  preamble_size: usize,
+  window: &mut VecDeque<u64>,
+  remaining: &mut Vec<u64>,
+) {
+  remaining.clear();
+  remaining.extend_from_slice(&numbers[preamble_size..]);
+
+  window.clear();
+  window.extend(&numbers[..preamble_size]);
+}
+
+fn find_first_step(data: &[&str], preamble_size: usize) -> Result<u64, AocError> {
+  let numbers = parse(data)?;
+  if numbers.len() <= preamble_size {
+    return Err(AocError::no_solution(format!(
+      "expected more than {} numbers, found {}",
+      preamble_size,
+      numbers.len()
+    )));
+  }
+
+  let mut window: VecDeque<u64> = VecDeque::with_capacity(preamble_size);
+  let mut remaining: Vec<u64> = Vec::with_capacity(numbers.len() - preamble_size);
+  initialize(&numbers, preamble_size, &mut window, &mut remaining);
+
+  for num in remaining {
+    if !window
+      .iter()
+      .combinations(2)
+      .any(|combo| combo.iter().copied().sum1::<u64>().unwrap() == num)
+    {
+      return Ok(num);
+    }
+    window.pop_front();
+    window.push_back(num);
+  }
+
+  Err(AocError::no_solution(format!(
+    "every number is a sum of two of the previous {}",
+    preamble_size
+  )))
+}
+
+fn find_weakness(data: &[&str], invalid_number: u64) -> Result<u64, AocError> {
+  let dataset = parse(data)?;
+
+  let mut found: Option<(usize, usize)> = None;
+
+  for window_size in 2..dataset.len() {
+    dataset.windows(window_size).enumerate().find(|(ix, w)| {
+      if w.iter().sum::<u64>() == invalid_number {
+        found = Some((*ix, window_size));
+        true
+      } else {
+        false
+      }
+    });
+  }
+
+  if let Some((window_start, window_size)) = found {
+    let min = dataset[window_start..window_start + window_size]
+      .iter()
+      .min()
+      .unwrap();
+    let max = dataset[window_start..window_start + window_size]
+      .iter()
+      .max()
+      .unwrap();
+    Ok(min + max)
+  } else {
+    Err(AocError::no_solution(format!(
+      "no contiguous run sums to {}",
+      invalid_number
+    )))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-09/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  fn test_find_first_step() {
+    let data = get_test_data();
+    assert_eq!(find_first_step(&data, 5), Ok(127));
+  }
+
+  #[test]
+  fn test_find_first_step_errors() {
+    assert_eq!(
+      find_first_step(&["1"], 25),
+      Err(AocError::no_solution(
+        "expected more than 25 numbers, found 1"
+      ))
+    );
+    assert_eq!(
+      find_first_step(&["1", "2", "3"], 2),
+      Err(AocError::no_solution(
+        "every number is a sum of two of the previous 2"
+      ))
+    );
+    assert_eq!(
+      find_first_step(&["1", "x2", "3"], 2),
+      Err(AocError::parse(1, "x2", "invalid digit found in string"))
+    );
+  }
+
+  #[test]
+  fn test_part_2() {
+    // 100 isn't a sum of two of 1 to 25, and 9 + 10 + ... + 16 = 100
+    let numbers: Vec<String> = (1..=25).chain(Some(100)).map(|n| n.to_string()).collect();
+    let data: Vec<&str> = numbers.iter().map(String::as_str).collect();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(100)));
+    assert_eq!(part_2(&data), Ok(Answer::Integer(25)));
+  }
+
+  #[test]
+  fn test_find_weakness() {
+    let data = get_test_data();
+    assert_eq!(find_weakness(&data, 127), Ok(62));
+    assert_eq!(
+      find_weakness(&data, 1),
+      Err(AocError::no_solution("no contiguous run sums to 1"))
+    );
+  }
+
+  #[test]
+  fn test_initialize() {
+    let data = get_test_data();
+    let preamble_size = 5;
+
+    let numbers = parse(&data).unwrap();
+    let mut window: VecDeque<u64> = VecDeque::with_capacity(preamble_size);
+    let mut remaining: Vec<u64> = Vec::with_capacity(data.len());
+    initialize(&numbers, preamble_size, &mut window, &mut remaining);
+
+    println!("window = {:?}", window);
+    assert_eq!(window.len(), preamble_size);
+    assert_eq!(window.pop_front().unwrap(), u64::from_str(data[0]).unwrap());
+
+    assert_eq!(remaining.len(), data.len() - preamble_size);
+    assert_eq!(*remaining.first().unwrap(), u64::from_str(data[5]).unwrap());
+  }
+}
@@ -1,38 +1,17 @@
-use common::{Day, Part};
+use crate::{Answer, AocError, Input};
 use std::collections::VecDeque;
 use std::iter::FromIterator;
 
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-10-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(1856, day.part_1.result);
-    assert_eq!(2314037239808, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-10-input.txt");
-    std::process::exit(1);
-  }
-}
-
-pub fn part_1(data: &[&str]) -> u64 {
-  let adapters = adapters(&data);
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let adapters = adapters(data)?;
   let count_1 = count_differences_of_span(&adapters, 1);
   let count_3 = count_differences_of_span(&adapters, 3);
 
-  (count_1 * count_3) as u64
+  Ok(Answer::from(count_1 * count_3))
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
-  let adapters = adapters(&data);
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let adapters = adapters(data)?;
   let differences = differences(&adapters);
   let indexes_of_threes = indexes_of_threes(&differences);
   let span_lengths_of_one = span_lengths_of_one(&indexes_of_threes);
@@ -40,19 +19,18 @@ pub fn part_2(data: &[&str]) -> u64 {
   let max_span = *(span_lengths_of_one.iter().max().unwrap());
   let tribonacci = tribonacci(max_span as usize);
 
-  span_lengths_of_one
-    .iter()
-    .map(|length| tribonacci[*length as usize] as u64)
-    .product::<u64>()
+  Ok(Answer::Integer(
+    span_lengths_of_one
+      .iter()
+      .map(|length| tribonacci[*length as usize] as u64)
+      .product::<u64>(),
+  ))
 }
 
-fn adapters(data: &[&str]) -> Vec<u32> {
-  let mut parsed_data: Vec<u32> = data
-    .iter()
-    .filter_map(|value| value.parse::<u32>().ok())
-    .collect();
+fn adapters(data: &[&str]) -> Result<Vec<u32>, AocError> {
+  let mut parsed_data: Vec<u32> = Input::new(data).numbers()?;
   parsed_data.sort_unstable();
-  parsed_data
+  Ok(parsed_data)
 }
 
 fn chain_adapters(adapters: &[u32]) -> VecDeque<u32> {
@@ -65,7 +43,7 @@ fn chain_adapters(adapters: &[u32]) -> VecDeque<u32> {
 }
 
 fn count_differences_of_span(adapters: &[u32], span: usize) -> usize {
-  differences(&adapters.to_vec())
+  differences(adapters)
     .iter()
     .filter(|d| **d == span as u32)
     .count()
@@ -110,74 +88,75 @@ fn tribonacci(to_n: usize) -> Vec<u32> {
 mod tests {
   use super::*;
 
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "large" => include_str!("../../../data/2020/examples/day-10/large.txt"),
+      _ => include_str!("../../../data/2020/examples/day-10/small.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
   #[test]
   fn test_part_1() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      assert_eq!(part_1(&data), 35);
+      let data = example("small");
+      assert_eq!(part_1(&data), Ok(Answer::Integer(35)));
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      assert_eq!(part_1(&data), 220);
+      let data = example("large");
+      assert_eq!(part_1(&data), Ok(Answer::Integer(220)));
     }
   }
 
   #[test]
   fn test_part_2() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      assert_eq!(part_2(&data), 8);
+      let data = example("small");
+      assert_eq!(part_2(&data), Ok(Answer::Integer(8)));
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      assert_eq!(part_2(&data), 19208);
+      let data = example("large");
+      assert_eq!(part_2(&data), Ok(Answer::Integer(19208)));
     }
   }
 
   #[test]
   fn test_adapters() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
+      let data = example("small");
       assert_eq!(
-        adapters(&data),
+        adapters(&data).unwrap(),
         vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19,]
       );
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
+      let data = example("large");
       assert_eq!(
-        adapters(&data),
+        adapters(&data).unwrap(),
         vec![
           1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31, 32, 33, 34, 35, 38,
           39, 42, 45, 46, 47, 48, 49,
         ]
       );
     }
+    assert_eq!(
+      adapters(&["1", "4", "five"]),
+      Err(AocError::parse(2, "five", "invalid digit found in string").at_column(0))
+    );
   }
 
   #[test]
   fn test_count_differences_of_span() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      let adapters = adapters(&data);
+      let data = example("small");
+      let adapters = adapters(&data).unwrap();
       assert_eq!(count_differences_of_span(&adapters, 1), 7);
       assert_eq!(count_differences_of_span(&adapters, 3), 5);
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      let adapters = adapters(&data);
+      let data = example("large");
+      let adapters = adapters(&data).unwrap();
       assert_eq!(count_differences_of_span(&adapters, 1), 22);
       assert_eq!(count_differences_of_span(&adapters, 3), 10);
     }
@@ -186,19 +165,16 @@ mod tests {
   #[test]
   fn test_differences() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      let adapters = adapters(&data);
+      let data = example("small");
+      let adapters = adapters(&data).unwrap();
       assert_eq!(
         differences(&adapters),
         vec![1, 3, 1, 1, 1, 3, 1, 1, 3, 1, 3, 3]
       );
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      let adapters = adapters(&data);
+      let data = example("large");
+      let adapters = adapters(&data).unwrap();
       assert_eq!(
         differences(&adapters),
         vec![
@@ -212,17 +188,14 @@ mod tests {
   #[test]
   fn test_indexes_of_threes() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      let adapters = adapters(&data);
+      let data = example("small");
+      let adapters = adapters(&data).unwrap();
       let differences = differences(&adapters);
       assert_eq!(indexes_of_threes(&differences), vec![2, 6, 9, 11, 12]);
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      let adapters = adapters(&data);
+      let data = example("large");
+      let adapters = adapters(&data).unwrap();
       let differences = differences(&adapters);
       assert_eq!(
         indexes_of_threes(&differences),
@@ -234,18 +207,15 @@ mod tests {
   #[test]
   fn test_span_lengths_of_one() {
     {
-      let data = vec!["16", "10", "15", "5", "1", "11", "7", "19", "6", "12", "4"];
-      let adapters = adapters(&data);
+      let data = example("small");
+      let adapters = adapters(&data).unwrap();
       let differences = differences(&adapters);
       let indexes_of_threes = indexes_of_threes(&differences);
       assert_eq!(span_lengths_of_one(&indexes_of_threes), vec![1, 3, 2, 1, 0]);
     }
     {
-      let data = vec![
-        "28", "33", "18", "42", "31", "14", "46", "20", "48", "47", "24", "23", "49", "45", "19",
-        "38", "39", "11", "1", "32", "25", "35", "8", "17", "7", "9", "4", "2", "34", "10", "3",
-      ];
-      let adapters = adapters(&data);
+      let data = example("large");
+      let adapters = adapters(&data).unwrap();
       let differences = differences(&adapters);
       let indexes_of_threes = indexes_of_threes(&differences);
       assert_eq!(
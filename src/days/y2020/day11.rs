@@ -0,0 +1,169 @@
+use crate::grid::{Direction, Grid, Point, ADJACENT};
+use crate::{Answer, AocError};
+use std::convert::TryFrom;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let mut room = Room::from(data)?;
+
+  for _ in 0..100 {
+    if !room.step() {
+      break;
+    }
+  }
+
+  Ok(Answer::from(room.count_occupied_seats()))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let mut room = Room::from(data)?;
+  room.far_sighted = true;
+  room.max_neighbors = 5;
+
+  for _ in 0..100 {
+    if !room.step() {
+      break;
+    }
+  }
+
+  Ok(Answer::from(room.count_occupied_seats()))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+  Floor,
+  Empty,
+  Occupied,
+}
+
+// the input only ever shows empty seats
+impl TryFrom<char> for Tile {
+  type Error = ();
+
+  fn try_from(c: char) -> Result<Tile, ()> {
+    match c {
+      '.' => Ok(Tile::Floor),
+      'L' => Ok(Tile::Empty),
+      _ => Err(()),
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Room {
+  // if true, people look past the floor to the first seat in each direction
+  far_sighted: bool,
+  max_neighbors: u8,
+  tiles: Grid<Tile>,
+}
+
+impl Room {
+  fn from(data: &[&str]) -> Result<Room, AocError> {
+    Ok(Room {
+      far_sighted: false,
+      max_neighbors: 4,
+      tiles: Grid::parse(data)?,
+    })
+  }
+
+  fn count_neighbors_of(&self, point: Point) -> u8 {
+    ADJACENT
+      .iter()
+      .filter(|direction| self.check_sightline(point, **direction))
+      .count() as u8
+  }
+
+  fn check_sightline(&self, point: Point, direction: Direction) -> bool {
+    let mut sightline = self.tiles.cast(point, direction);
+    let seen = if self.far_sighted {
+      sightline.find(|(_, tile)| **tile != Tile::Floor)
+    } else {
+      sightline.next()
+    };
+
+    matches!(seen, Some((_, Tile::Occupied)))
+  }
+
+  fn count_occupied_seats(&self) -> u32 {
+    self
+      .tiles
+      .iter()
+      .filter(|(_, tile)| **tile == Tile::Occupied)
+      .count() as u32
+  }
+
+  fn step(&mut self) -> bool {
+    let mut next_gen = self.tiles.clone();
+    let mut changed = false;
+
+    for (point, tile) in self.tiles.iter() {
+      let next = match (tile, self.count_neighbors_of(point)) {
+        (Tile::Empty, 0) => Tile::Occupied,
+        (Tile::Occupied, neighbors) if neighbors >= self.max_neighbors => Tile::Empty,
+        _ => *tile,
+      };
+      if next != *tile {
+        next_gen[point] = next;
+        changed = true;
+      }
+    }
+
+    self.tiles = next_gen;
+    changed
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-11/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    assert_eq!(part_1(&get_test_data()), Ok(Answer::Integer(37)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    assert_eq!(part_2(&get_test_data()), Ok(Answer::Integer(26)));
+  }
+
+  #[test]
+  fn test_room_count_neighbors_of() {
+    let data = ["LLL", "LLL", "LLL"];
+
+    let mut room = Room::from(&data).unwrap();
+    for point in [(0, 0), (1, 0), (0, 1)].iter() {
+      room.tiles[*point] = Tile::Occupied;
+    }
+
+    assert_eq!(room.count_neighbors_of((0, 0)), 2);
+    assert_eq!(room.count_neighbors_of((0, 1)), 2);
+    assert_eq!(room.count_neighbors_of((0, 2)), 1);
+    assert_eq!(room.count_neighbors_of((1, 0)), 2);
+    assert_eq!(room.count_neighbors_of((1, 1)), 3);
+    assert_eq!(room.count_neighbors_of((1, 2)), 1);
+    assert_eq!(room.count_neighbors_of((2, 0)), 1);
+    assert_eq!(room.count_neighbors_of((2, 1)), 1);
+    assert_eq!(room.count_neighbors_of((2, 2)), 0);
+  }
+
+  #[test]
+  fn test_room_from() {
+    let room = Room::from(&get_test_data()).unwrap();
+
+    assert_eq!(room.tiles.width(), 10);
+    assert_eq!(room.tiles.height(), 10);
+    assert_eq!(room.tiles[(0, 0)], Tile::Empty);
+    assert_eq!(room.tiles[(3, 6)], Tile::Floor);
+
+    assert_eq!(
+      Room::from(&["L.L", "L#L"]).unwrap_err(),
+      AocError::parse(1, "L#L", "don't know what to do with '#'").at_column(1)
+    );
+  }
+}
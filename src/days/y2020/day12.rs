@@ -1,27 +1,7 @@
-use common::{Day, Part};
+use crate::{Answer, AocError};
 use regex::Regex;
 use std::fmt;
-
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-12-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(362, day.part_1.result);
-    assert_eq!(29895, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-12-input.txt");
-    std::process::exit(1);
-  }
-}
+use std::num::ParseIntError;
 
 enum Command {
   North(i32),
@@ -58,6 +38,7 @@ impl Point {
     (self.lat - other.lat).abs() + (self.lon - other.lon).abs()
   }
 
+  // angle is a multiple of 90; see interpret_command_line()
   fn rotate_around(&mut self, other: &Point, angle: i32) {
     let delta_lat = self.lat - other.lat;
     let delta_lon = self.lon - other.lon;
@@ -71,11 +52,10 @@ impl Point {
         self.lat = other.lat - delta_lat;
         self.lon = other.lon - delta_lon;
       }
-      270 => {
+      _ => {
         self.lat = other.lat + delta_lon;
         self.lon = other.lon - delta_lat;
       }
-      angle => panic!("unexpected angle: {}", angle),
     }
   }
 }
@@ -105,8 +85,8 @@ impl ShipMk1 {
         0 => self.execute(Command::North(distance)),
         90 => self.execute(Command::East(distance)),
         180 => self.execute(Command::South(distance)),
-        270 => self.execute(Command::West(distance)),
-        angle => panic!("unexpected heading: {}", angle),
+        // the heading only ever turns by multiples of 90, so this is 270
+        _ => self.execute(Command::West(distance)),
       },
     }
   }
@@ -141,36 +121,47 @@ impl ShipMk2 {
   }
 }
 
-pub fn part_1(data: &[&str]) -> u64 {
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
   let mut ship = ShipMk1::new();
 
-  for line in data {
-    if let Some(command) = interpret_command_line(line) {
-      ship.execute(command);
-    }
+  for (ix, line) in data.iter().enumerate() {
+    ship.execute(interpret_command_line(ix, line)?);
   }
 
-  ship.location.manhattan_distance_from(Point::new(0, 0)) as u64
+  Ok(Answer::Integer(
+    ship.location.manhattan_distance_from(Point::new(0, 0)) as u64,
+  ))
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
   let mut ship = ShipMk2::new();
 
-  for line in data {
-    if let Some(command) = interpret_command_line(line) {
-      ship.execute(command);
-    }
+  for (ix, line) in data.iter().enumerate() {
+    ship.execute(interpret_command_line(ix, line)?);
   }
 
-  ship.location.manhattan_distance_from(Point::new(0, 0)) as u64
+  Ok(Answer::Integer(
+    ship.location.manhattan_distance_from(Point::new(0, 0)) as u64,
+  ))
 }
 
-fn interpret_command_line(line: &str) -> Option<Command> {
-  let regex = Regex::new(r"([EFLNRSW])(\d+)").unwrap();
+fn interpret_command_line(ix: usize, line: &str) -> Result<Command, AocError> {
+  let regex = Regex::new(r"^(\D)(\d+)$").unwrap();
 
   if let Some(captures) = regex.captures(line) {
     let command_selector: &str = captures.get(1).unwrap().as_str();
-    let amount: i32 = captures.get(2).unwrap().as_str().parse().ok().unwrap();
+    let amount: i32 = captures
+      .get(2)
+      .unwrap()
+      .as_str()
+      .parse()
+      .map_err(|e: ParseIntError| AocError::parse(ix, line, e.to_string()).at_column(1))?;
+
+    if (command_selector == "L" || command_selector == "R") && amount % 90 != 0 {
+      return Err(
+        AocError::parse(ix, line, "can only turn by a multiple of 90 degrees").at_column(1),
+      );
+    }
 
     let command = match command_selector {
       "N" => Command::North(amount),
@@ -180,12 +171,16 @@ fn interpret_command_line(line: &str) -> Option<Command> {
       "L" => Command::Left(amount),
       "R" => Command::Right(amount),
       "F" => Command::Forward(amount),
-      other => panic!("unexpected command selector: {}", other),
+      other => {
+        return Err(
+          AocError::parse(ix, line, format!("unexpected command selector: {}", other)).at_column(0),
+        )
+      }
     };
 
-    Some(command)
+    Ok(command)
   } else {
-    None
+    Err(AocError::parse(ix, line, "expected a command like 'F10'"))
   }
 }
 
@@ -201,16 +196,54 @@ fn normalize_angle(angle: i32) -> i32 {
 mod tests {
   use super::*;
 
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-12/example.txt")
+      .lines()
+      .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec!["F10", "N3", "F7", "R90", "F11"];
-    assert_eq!(part_1(&data), 25);
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(25)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec!["F10", "N3", "F7", "R90", "F11"];
-    assert_eq!(part_2(&data), 286);
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(286)));
+  }
+
+  #[test]
+  fn test_interpret_command_line() {
+    assert!(matches!(
+      interpret_command_line(0, "F10"),
+      Ok(Command::Forward(10))
+    ));
+    assert!(matches!(
+      interpret_command_line(0, "R270"),
+      Ok(Command::Right(270))
+    ));
+    assert!(matches!(
+      interpret_command_line(4, "X10"),
+      Err(AocError::Parse {
+        line: 5,
+        column: Some(1),
+        ..
+      })
+    ));
+    assert_eq!(
+      interpret_command_line(0, "R45").err(),
+      Some(AocError::parse(0, "R45", "can only turn by a multiple of 90 degrees").at_column(1))
+    );
+    assert!(matches!(
+      interpret_command_line(4, "F"),
+      Err(AocError::Parse {
+        line: 5,
+        column: None,
+        ..
+      })
+    ));
   }
 
   #[test]
@@ -1,25 +1,5 @@
-use common::{Day, Part};
-
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-13-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(2406, day.part_1.result);
-    assert_eq!(225850756401039, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-13-input.txt");
-    std::process::exit(1);
-  }
-}
+use crate::{Answer, AocError};
+use std::num::ParseIntError;
 
 struct Timetable {
   timestamp: u64,
@@ -34,25 +14,39 @@ impl Timetable {
     }
   }
 
-  fn from(data: &[&str]) -> Timetable {
+  fn from(data: &[&str]) -> Result<Timetable, AocError> {
     let mut timetable = Timetable::new();
 
     let mut iter = data.iter();
 
-    if let Some(timestamp) = iter.next() {
-      if let Some(ids) = iter.next() {
-        timetable.timestamp = timestamp.parse().ok().unwrap();
-        timetable.ids = ids
-          .split(',')
-          .map(|id| match id {
-            "x" => None,
-            num => num.parse().ok(),
-          })
-          .collect();
+    if let (Some(timestamp), Some(ids)) = (iter.next(), iter.next()) {
+      timetable.timestamp = timestamp
+        .parse()
+        .map_err(|e: ParseIntError| AocError::parse(0, timestamp, e.to_string()))?;
+
+      let mut column = 0;
+      for id in ids.split(',') {
+        timetable.ids.push(match id {
+          "x" => None,
+          num => {
+            let id = num.parse().map_err(|e: ParseIntError| {
+              AocError::parse(1, ids, e.to_string()).at_column(column)
+            })?;
+            if id == 0 {
+              return Err(AocError::parse(1, ids, "a bus id can't be 0").at_column(column));
+            }
+            Some(id)
+          }
+        });
+        column += id.len() + 1;
       }
-    }
 
-    timetable
+      Ok(timetable)
+    } else {
+      Err(AocError::no_solution(
+        "expected a timestamp line followed by a line of bus ids",
+      ))
+    }
   }
 
   fn departure_delay_for_bus(&self, id: u64) -> u64 {
@@ -66,38 +60,39 @@ impl Timetable {
       .enumerate()
       .filter(|(_, id)| id.is_some())
       .map(|(ix, id)| {
-        (
-          id.unwrap(),
-          normalized_mod((id.unwrap() - ix as u64) as i64, id.unwrap() as i64) as u64,
-        )
+        let id = id.unwrap();
+        (id, normalized_mod(id as i64 - ix as i64, id as i64) as u64)
       })
       .collect()
   }
 
-  fn shortest_delay(&self) -> (u64, u64) {
+  fn shortest_delay(&self) -> Option<(u64, u64)> {
     self
       .ids
       .iter()
       .filter(|id| id.is_some())
       .map(|id| (id.unwrap(), self.departure_delay_for_bus(id.unwrap())))
       .min_by(|(_, va), (_, vb)| va.cmp(vb))
-      .unwrap()
   }
 }
 
-pub fn part_1(data: &[&str]) -> u64 {
-  let timetable = Timetable::from(&data);
-  let (id, delay) = timetable.shortest_delay();
-  id as u64 * delay
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let timetable = Timetable::from(data)?;
+  let (id, delay) = timetable
+    .shortest_delay()
+    .ok_or_else(|| AocError::no_solution("every bus is out of service"))?;
+  Ok(Answer::from(id * delay))
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
-  let timetable = Timetable::from(&data);
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let timetable = Timetable::from(data)?;
   let remainders = timetable.remainders();
   let residues = residues(&remainders);
   let modulii = modulii(&remainders);
 
-  chinese_remainder(&residues, &modulii).unwrap() as u64
+  chinese_remainder(&residues, &modulii)
+    .map(|timestamp| Answer::Integer(timestamp as u64))
+    .ok_or_else(|| AocError::no_solution("the bus ids aren't pairwise coprime"))
 }
 
 // https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
@@ -155,16 +150,45 @@ fn residues(pairs: &[(u64, u64)]) -> Vec<i64> {
 mod tests {
   use super::*;
 
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-13/example.txt")
+      .lines()
+      .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    assert_eq!(part_1(&data), 295);
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(295)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    assert_eq!(part_2(&data), 1068781);
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(1068781)));
+  }
+
+  #[test]
+  fn test_late_bus() {
+    // the bus at offset 4 has an id smaller than its offset
+    let data = vec!["939", "5,x,x,x,3"];
+    assert_eq!(part_2(&data), Ok(Answer::Integer(5)));
+  }
+
+  #[test]
+  fn test_timetable_from_errors() {
+    assert_eq!(
+      Timetable::from(&["939", "7,13,x,y,59"]).err(),
+      Some(AocError::parse(1, "7,13,x,y,59", "invalid digit found in string").at_column(7))
+    );
+    assert_eq!(
+      Timetable::from(&["939", "7,0,x"]).err(),
+      Some(AocError::parse(1, "7,0,x", "a bus id can't be 0").at_column(2))
+    );
+    assert!(matches!(
+      Timetable::from(&["939"]),
+      Err(AocError::NoSolution(_))
+    ));
   }
 
   #[test]
@@ -212,8 +236,8 @@ mod tests {
 
   #[test]
   fn test_timetable_from() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    let timetable = Timetable::from(&data);
+    let data = get_test_data();
+    let timetable = Timetable::from(&data).unwrap();
     assert_eq!(timetable.timestamp, 939);
     assert_eq!(
       timetable.ids,
@@ -232,8 +256,8 @@ mod tests {
 
   #[test]
   fn test_timetable_departure_delay_for_bus() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    let timetable = Timetable::from(&data);
+    let data = get_test_data();
+    let timetable = Timetable::from(&data).unwrap();
     assert_eq!(timetable.departure_delay_for_bus(7), 6);
     assert_eq!(timetable.departure_delay_for_bus(13), 10);
     assert_eq!(timetable.departure_delay_for_bus(59), 5);
@@ -243,8 +267,8 @@ mod tests {
 
   #[test]
   fn test_timetable_remainders() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    let timetable = Timetable::from(&data);
+    let data = get_test_data();
+    let timetable = Timetable::from(&data).unwrap();
     assert_eq!(
       timetable.remainders(),
       vec![(7, 0), (13, 12), (59, 55), (31, 25), (19, 12)]
@@ -253,8 +277,8 @@ mod tests {
 
   #[test]
   fn test_timetable_shortest_delay() {
-    let data = vec!["939", "7,13,x,x,59,x,31,19"];
-    let timetable = Timetable::from(&data);
-    assert_eq!(timetable.shortest_delay(), (59, 5));
+    let data = get_test_data();
+    let timetable = Timetable::from(&data).unwrap();
+    assert_eq!(timetable.shortest_delay(), Some((59, 5)));
   }
 }
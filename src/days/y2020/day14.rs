@@ -1,31 +1,10 @@
-use common::{Day, Part};
-use regex::Regex;
+use crate::{Answer, AocError, Input, Solution};
+use regex::{Match, Regex};
 use std::collections::HashMap;
 use std::str::FromStr;
 
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-14-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(7997531787333, day.part_1.result);
-    assert_eq!(3564822193820, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-14-input.txt");
-    std::process::exit(1);
-  }
-}
-
 #[derive(Clone, Debug, PartialEq)]
-enum Instruction {
+pub enum Instruction {
   Mask(String),
   Mem(String, String),
 }
@@ -46,8 +25,8 @@ impl PortComputerMk1 {
   fn execute(&mut self, program: &[Instruction]) {
     for instruction in program {
       match instruction {
-        Instruction::Mask(mask) => self.exec_mask(&mask),
-        Instruction::Mem(address, value) => self.exec_mem(&address, &value),
+        Instruction::Mask(mask) => self.exec_mask(mask),
+        Instruction::Mem(address, value) => self.exec_mem(address, value),
       }
     }
   }
@@ -79,8 +58,8 @@ impl PortComputerMk2 {
   fn execute(&mut self, program: &[Instruction]) {
     for instruction in program {
       match instruction {
-        Instruction::Mask(mask) => self.exec_mask(&mask),
-        Instruction::Mem(address, value) => self.exec_mem(&address, &value),
+        Instruction::Mask(mask) => self.exec_mask(mask),
+        Instruction::Mem(address, value) => self.exec_mem(address, value),
       }
     }
   }
@@ -96,34 +75,46 @@ impl PortComputerMk2 {
   }
 }
 
-pub fn part_1(data: &[&str]) -> u64 {
-  let mut pc = PortComputerMk1::new();
-  let program = compile(data);
-  pc.execute(&program);
+pub struct Day14;
 
-  pc.mem
-    .values()
-    .map(|v| u64::from_str_radix(v, 2).unwrap())
-    .sum()
-}
+impl<'a> Solution<'a> for Day14 {
+  type Parsed = Vec<Instruction>;
+
+  fn parse(input: &Input<'a>) -> Result<Vec<Instruction>, AocError> {
+    compile(input.lines())
+  }
+
+  fn part_1(program: &Vec<Instruction>) -> Result<Answer, AocError> {
+    let mut pc = PortComputerMk1::new();
+    pc.execute(program);
 
-pub fn part_2(data: &[&str]) -> u64 {
-  let mut pc = PortComputerMk2::new();
-  let program = compile(data);
-  pc.execute(&program);
+    Ok(Answer::Integer(
+      pc.mem
+        .values()
+        .map(|v| u64::from_str_radix(v, 2).unwrap())
+        .sum(),
+    ))
+  }
+
+  fn part_2(program: &Vec<Instruction>) -> Result<Answer, AocError> {
+    let mut pc = PortComputerMk2::new();
+    pc.execute(program);
 
-  pc.mem
-    .values()
-    .map(|v| u64::from_str_radix(v, 2).unwrap())
-    .sum()
+    Ok(Answer::Integer(
+      pc.mem
+        .values()
+        .map(|v| u64::from_str_radix(v, 2).unwrap())
+        .sum(),
+    ))
+  }
 }
 
-fn compile(data: &[&str]) -> Vec<Instruction> {
+fn compile(data: &[&str]) -> Result<Vec<Instruction>, AocError> {
   let re_mask = Regex::new(r"mask = ([01X]{36})").unwrap();
   let re_mem = Regex::new(r"mem\[(\d+)\] = (\d+)").unwrap();
   let mut program: Vec<Instruction> = vec![];
 
-  for line in data {
+  for (ix, line) in data.iter().enumerate() {
     if re_mask.is_match(line) {
       let captures = re_mask.captures(line).unwrap();
 
@@ -133,19 +124,27 @@ fn compile(data: &[&str]) -> Vec<Instruction> {
     } else if re_mem.is_match(line) {
       let captures = re_mem.captures(line).unwrap();
 
-      let address = u64::from_str(captures.get(1).unwrap().as_str()).unwrap();
+      let address = parse_36_bits(ix, line, captures.get(1).unwrap())?;
       let address_bits = format!("{:0>36b}", address);
 
-      let value = u64::from_str(captures.get(2).unwrap().as_str()).unwrap();
+      let value = parse_36_bits(ix, line, captures.get(2).unwrap())?;
       let value_bits = format!("{:0>36b}", value);
 
       program.push(Instruction::Mem(address_bits, value_bits));
     } else {
-      panic!("can't compile line: {}", line);
+      return Err(AocError::parse(ix, line, "can't compile line"));
     }
   }
 
-  program
+  Ok(program)
+}
+
+fn parse_36_bits(ix: usize, line: &str, m: Match) -> Result<u64, AocError> {
+  match u64::from_str(m.as_str()) {
+    Ok(value) if value < (1 << 36) => Ok(value),
+    Ok(_) => Err(AocError::parse(ix, line, "value doesn't fit in 36 bits").at_column(m.start())),
+    Err(e) => Err(AocError::parse(ix, line, e.to_string()).at_column(m.start())),
+  }
 }
 
 fn floating_addresses(address: &str) -> Vec<String> {
@@ -195,50 +194,40 @@ fn mask_mem_value(value: &str, mask: &str) -> String {
 mod tests {
   use super::*;
 
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "part-1" => include_str!("../../../data/2020/examples/day-14/part-1.txt"),
+      _ => include_str!("../../../data/2020/examples/day-14/part-2.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec![
-      "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
-      "mem[8] = 11",
-      "mem[7] = 101",
-      "mem[8] = 0",
-    ];
-    assert_eq!(part_1(&data), 165);
+    let data = example("part-1");
+    assert_eq!(Day14::run_part_1(&data), Ok(Answer::Integer(165)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec![
-      "mask = 000000000000000000000000000000X1001X",
-      "mem[42] = 100",
-      "mask = 00000000000000000000000000000000X0XX",
-      "mem[26] = 1",
-    ];
-    assert_eq!(part_2(&data), 208);
+    let data = example("part-2");
+    assert_eq!(Day14::run_part_2(&data), Ok(Answer::Integer(208)));
   }
 
   #[test]
   fn test_port_computer_mk1_execute() {
     let mut pc = PortComputerMk1::new();
-    let program = compile(&[
-      "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
-      "mem[8] = 11",
-      "mem[7] = 101",
-      "mem[8] = 0",
-    ]);
+    let program = compile(&example("part-1")).unwrap();
     pc.execute(&program);
 
     assert_eq!(pc.mask, "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X");
     assert_eq!(
-      pc.mem
-        .get(&"000000000000000000000000000000000111".to_string())
-        .unwrap(),
+      pc.mem.get("000000000000000000000000000000000111").unwrap(),
       "000000000000000000000000000001100101"
     );
     assert_eq!(
-      pc.mem
-        .get(&"000000000000000000000000000000001000".to_string())
-        .unwrap(),
+      pc.mem.get("000000000000000000000000000000001000").unwrap(),
       "000000000000000000000000000001000000"
     );
   }
@@ -246,15 +235,10 @@ mod tests {
   #[test]
   fn test_port_computer_mk2_execute() {
     let mut pc = PortComputerMk2::new();
-    let program = compile(&[
-      "mask = 000000000000000000000000000000X1001X",
-      "mem[42] = 100",
-      "mask = 00000000000000000000000000000000X0XX",
-      "mem[26] = 1",
-    ]);
+    let program = compile(&example("part-2")).unwrap();
     pc.execute(&program);
 
-    for address in vec![
+    for address in [
       // these two get overwritten in the next write
       // "000000000000000000000000000000011010".to_string(),
       // "000000000000000000000000000000011011".to_string(),
@@ -267,7 +251,7 @@ mod tests {
       );
     }
 
-    for address in vec![
+    for address in [
       "000000000000000000000000000000010000".to_string(),
       "000000000000000000000000000000010001".to_string(),
       "000000000000000000000000000000010010".to_string(),
@@ -286,14 +270,9 @@ mod tests {
 
   #[test]
   fn test_compile() {
-    let program = compile(&[
-      "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
-      "mem[8] = 11",
-      "mem[7] = 101",
-      "mem[8] = 0",
-    ]);
+    let program = compile(&example("part-1")).unwrap();
     assert_eq!(
-      *program.get(0).unwrap(),
+      *program.first().unwrap(),
       Instruction::Mask("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".to_string()),
     );
     assert_eq!(
@@ -319,14 +298,30 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_compile_errors() {
+    assert_eq!(
+      compile(&[
+        "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+        "mem[8] = 11",
+        "mem[8] == 0"
+      ]),
+      Err(AocError::parse(2, "mem[8] == 0", "can't compile line"))
+    );
+    assert_eq!(
+      compile(&["mem[8] = 68719476736"]),
+      Err(AocError::parse(0, "mem[8] = 68719476736", "value doesn't fit in 36 bits").at_column(9))
+    );
+  }
+
   #[test]
   fn test_floating_addresses() {
     assert_eq!(
-      floating_addresses(&"000000000000000000000000000000010010".to_string()),
+      floating_addresses("000000000000000000000000000000010010"),
       vec!["000000000000000000000000000000010010".to_string()]
     );
     assert_eq!(
-      floating_addresses(&"000000000000000000000000000000X1001X".to_string()),
+      floating_addresses("000000000000000000000000000000X1001X"),
       vec![
         "000000000000000000000000000000010010".to_string(),
         "000000000000000000000000000000010011".to_string(),
@@ -335,7 +330,7 @@ mod tests {
       ]
     );
     assert_eq!(
-      floating_addresses(&"00000000000000000000000000000001X0XX".to_string()),
+      floating_addresses("00000000000000000000000000000001X0XX"),
       vec![
         "000000000000000000000000000000010000".to_string(),
         "000000000000000000000000000000010001".to_string(),
@@ -351,19 +346,19 @@ mod tests {
 
   #[test]
   fn test_mask_address() {
-    assert_eq!(mask_address("0000", &"0000"), "0000");
-    assert_eq!(mask_address("1111", &"0000"), "1111");
-    assert_eq!(mask_address("0000", &"1111"), "1111");
-    assert_eq!(mask_address("1111", &"1111"), "1111");
-    assert_eq!(mask_address("0000", &"1X0X"), "1X0X");
-    assert_eq!(mask_address("1111", &"1X0X"), "1X1X");
+    assert_eq!(mask_address("0000", "0000"), "0000");
+    assert_eq!(mask_address("1111", "0000"), "1111");
+    assert_eq!(mask_address("0000", "1111"), "1111");
+    assert_eq!(mask_address("1111", "1111"), "1111");
+    assert_eq!(mask_address("0000", "1X0X"), "1X0X");
+    assert_eq!(mask_address("1111", "1X0X"), "1X1X");
   }
 
   #[test]
   fn test_mask_mem_value() {
-    assert_eq!(mask_mem_value("0000", &"XXXX"), "0000");
-    assert_eq!(mask_mem_value("1111", &"XXXX"), "1111");
-    assert_eq!(mask_mem_value("0000", &"X10X"), "0100");
-    assert_eq!(mask_mem_value("1111", &"X10X"), "1101");
+    assert_eq!(mask_mem_value("0000", "XXXX"), "0000");
+    assert_eq!(mask_mem_value("1111", "XXXX"), "1111");
+    assert_eq!(mask_mem_value("0000", "X10X"), "0100");
+    assert_eq!(mask_mem_value("1111", "X10X"), "1101");
   }
 }
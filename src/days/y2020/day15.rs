@@ -0,0 +1,138 @@
+use crate::{Answer, AocError, Input};
+use std::collections::{HashMap, VecDeque};
+use std::iter::FromIterator;
+
+// the starting numbers are on the puzzle page rather than in a download, so they're registered
+// as the day's input; others can be given comma-separated or one per line, e.g.
+//
+//   aoc run 15 --input 0,3,6
+pub const INPUT: &str = "0,1,5,10,3,12,19";
+
+struct Game {
+  turn: u32,
+  list: VecDeque<u32>,
+  memory: HashMap<u32, u32>,
+}
+
+impl Game {
+  fn new(initial: &[u32]) -> Game {
+    Game {
+      turn: 1,
+      list: VecDeque::from_iter(initial.iter().cloned()),
+      memory: HashMap::new(),
+    }
+  }
+
+  fn next_number(&self) -> u32 {
+    self.list.front().cloned().unwrap()
+  }
+
+  fn take_turn(&mut self) {
+    if let Some(number) = self.list.front().cloned() {
+      if let Some(last_spoken) = self.memory.get(&number).cloned() {
+        // number was spoken before
+        self.memory.insert(number, self.turn);
+        self.list.push_back(self.turn - last_spoken);
+      } else {
+        // number was not spoken before
+        self.memory.insert(number, self.turn);
+        if self.list.len() == 1 {
+          self.list.push_back(0)
+        };
+      }
+      self.list.pop_front();
+      self.turn += 1;
+    } else {
+      panic!("list is empty");
+    }
+  }
+}
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let initial = parse_starting_numbers(data)?;
+
+  let mut game = Game::new(&initial);
+  for _ in 1..2020 {
+    game.take_turn();
+  }
+
+  Ok(Answer::from(game.next_number()))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let initial = parse_starting_numbers(data)?;
+
+  let mut game = Game::new(&initial);
+  for _ in 1..30_000_000 {
+    game.take_turn();
+  }
+
+  Ok(Answer::from(game.next_number()))
+}
+
+// one number per line, or comma-separated, or a mix of both
+fn parse_starting_numbers(data: &[&str]) -> Result<Vec<u32>, AocError> {
+  let numbers: Vec<u32> = Input::new(data).numbers()?;
+
+  if numbers.is_empty() {
+    return Err(AocError::no_solution("no starting numbers"));
+  }
+
+  Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_part_1() {
+    assert_eq!(part_1(&["0", "3", "6"]), Ok(Answer::Integer(436)));
+    assert_eq!(part_1(&["1", "3", "2"]), Ok(Answer::Integer(1)));
+    assert_eq!(part_1(&["2", "1", "3"]), Ok(Answer::Integer(10)));
+    assert_eq!(part_1(&["1", "2", "3"]), Ok(Answer::Integer(27)));
+    assert_eq!(part_1(&["2", "3", "1"]), Ok(Answer::Integer(78)));
+    assert_eq!(part_1(&["3", "2", "1"]), Ok(Answer::Integer(438)));
+    assert_eq!(part_1(&["3", "1", "2"]), Ok(Answer::Integer(1836)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    assert_eq!(part_2(&["0", "3", "6"]), Ok(Answer::Integer(175594)));
+    assert_eq!(part_2(&["1", "3", "2"]), Ok(Answer::Integer(2578)));
+    assert_eq!(part_2(&["2", "1", "3"]), Ok(Answer::Integer(3544142)));
+    assert_eq!(part_2(&["1", "2", "3"]), Ok(Answer::Integer(261214)));
+    assert_eq!(part_2(&["2", "3", "1"]), Ok(Answer::Integer(6895259)));
+    assert_eq!(part_2(&["3", "2", "1"]), Ok(Answer::Integer(18)));
+    assert_eq!(part_2(&["3", "1", "2"]), Ok(Answer::Integer(362)));
+  }
+
+  #[test]
+  fn test_parse_starting_numbers() {
+    assert_eq!(parse_starting_numbers(&["0", "3", "6"]), Ok(vec![0, 3, 6]));
+    assert_eq!(parse_starting_numbers(&["0,3,6"]), Ok(vec![0, 3, 6]));
+    assert_eq!(
+      parse_starting_numbers(&["0", "three", "6"]),
+      Err(AocError::parse(1, "three", "invalid digit found in string").at_column(0))
+    );
+    assert_eq!(
+      parse_starting_numbers(&["0,3,six"]),
+      Err(AocError::parse(0, "0,3,six", "invalid digit found in string").at_column(4))
+    );
+    assert!(parse_starting_numbers(&[]).is_err());
+  }
+
+  #[test]
+  fn test_input() {
+    assert_eq!(part_1(&[INPUT]), Ok(Answer::Integer(1373)));
+  }
+
+  #[test]
+  fn test_take_turn() {
+    let mut game = Game::new(&[0, 3, 6]);
+    for _ in 1..10 {
+      game.take_turn();
+    }
+    assert_eq!(game.next_number(), 0);
+  }
+}
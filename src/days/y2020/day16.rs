@@ -0,0 +1,389 @@
+use crate::{Answer, AocError, Input, Section, Solution};
+use regex::{Match, Regex};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub struct Day16;
+
+pub struct Notes<'a> {
+  rules: Rules<'a>,
+  my_ticket: Option<Vec<u32>>,
+  nearby_tickets: Vec<Vec<u32>>,
+}
+
+impl<'a> Solution<'a> for Day16 {
+  type Parsed = Notes<'a>;
+
+  fn parse(input: &Input<'a>) -> Result<Notes<'a>, AocError> {
+    let (rules_data, my_ticket_data, nearby_tickets_data) = split_into_sections(input.lines())?;
+
+    Ok(Notes {
+      rules: parse_rules(rules_data)?,
+      my_ticket: parse_tickets(my_ticket_data)?.into_iter().next(),
+      nearby_tickets: parse_tickets(nearby_tickets_data)?,
+    })
+  }
+
+  fn part_1(notes: &Notes) -> Result<Answer, AocError> {
+    let valid_ranges: Vec<RangeInclusive<u32>> = valid_ranges(&notes.rules);
+
+    let mut invalid_values: Vec<u32> = vec![];
+    for ticket in &notes.nearby_tickets {
+      for value in ticket {
+        if !valid_ranges.iter().any(|r| r.contains(value)) {
+          invalid_values.push(*value);
+        }
+      }
+    }
+
+    Ok(Answer::from(invalid_values.iter().sum::<u32>()))
+  }
+
+  fn part_2(notes: &Notes) -> Result<Answer, AocError> {
+    let rules = &notes.rules;
+
+    // remove invalid tickets
+    let mut nearby_tickets: Vec<Vec<u32>> = notes.nearby_tickets.clone();
+    retain_valid_tickets(&mut nearby_tickets, rules);
+
+    // every ticket is as wide as the first (see parse_tickets())
+    let width = notes.nearby_tickets.first().map_or(0, Vec::len);
+
+    // start with every rule being possible for every field
+    let mut possible_rules_for_field: Vec<HashSet<&str>> = vec![HashSet::new(); width];
+    for name in rules.keys() {
+      for field in possible_rules_for_field.iter_mut() {
+        field.insert(name);
+      }
+    }
+
+    // for each field in a ticket, remove any rule from the possible set if the value is invalid
+    for ticket in nearby_tickets {
+      for (field_number, field_value) in ticket.iter().enumerate() {
+        for (rule_name, (range_1, range_2)) in rules {
+          if !range_1.contains(field_value) && !range_2.contains(field_value) {
+            possible_rules_for_field[field_number].remove(rule_name);
+          }
+        }
+      }
+    }
+
+    // find fields which have only one possible rule, then remove them from the set of possibles for other fields
+    // repeat until a unique solution is found, or we've iterated once per field
+    let mut field_rules: Vec<Option<&str>> = vec![None; width];
+    for _ in 0..width {
+      match &possible_rules_for_field
+        .iter()
+        .enumerate()
+        .find(|(_, s)| s.len() == 1)
+      {
+        None => break,
+        Some((ix, possible)) => {
+          let rule_name: &str = possible.iter().cloned().next().unwrap();
+          field_rules[*ix] = Some(rule_name);
+          for s in &mut possible_rules_for_field {
+            s.remove(rule_name);
+          }
+        }
+      }
+    }
+
+    // find the fields corresponding with rules starting with "departure"
+    let indexes: Vec<usize> = field_rules
+      .iter()
+      .enumerate()
+      .filter(|(_, opt)| opt.is_some())
+      .map(|(ix, opt)| (ix, opt.unwrap()))
+      .filter(|(_, name)| name.starts_with("departure"))
+      .map(|(ix, _)| ix)
+      .collect();
+
+    let my_ticket = notes
+      .my_ticket
+      .as_ref()
+      .ok_or_else(|| AocError::no_solution("your ticket is missing"))?;
+    indexes
+      .iter()
+      .map(|&ix| {
+        my_ticket
+          .get(ix)
+          .map(|&value| value as u64)
+          .ok_or_else(|| AocError::no_solution(format!("your ticket has no field {}", ix + 1)))
+      })
+      .product::<Result<u64, _>>()
+      .map(Answer::from)
+  }
+}
+
+type Rules<'a> = HashMap<&'a str, (RangeInclusive<u32>, RangeInclusive<u32>)>;
+
+fn parse_rules<'a>(data: Input<'a>) -> Result<Rules<'a>, AocError> {
+  let regex = Regex::new(r"^(.+): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
+  let mut vec: Vec<(&str, RangeInclusive<u32>, RangeInclusive<u32>)> = vec![];
+
+  for (ix, line) in data.lines().iter().enumerate() {
+    let ix = data.offset() + ix;
+    let captures = regex
+      .captures(line)
+      .ok_or_else(|| AocError::parse(ix, line, "expected \"<name>: <a>-<b> or <c>-<d>\""))?;
+    let name = captures.get(1).unwrap().as_str();
+    let range_1_start = parse_number(ix, line, captures.get(2).unwrap())?;
+    let range_1_end = parse_number(ix, line, captures.get(3).unwrap())?;
+    let range_2_start = parse_number(ix, line, captures.get(4).unwrap())?;
+    let range_2_end = parse_number(ix, line, captures.get(5).unwrap())?;
+    vec.push((
+      name,
+      RangeInclusive::new(range_1_start, range_1_end),
+      RangeInclusive::new(range_2_start, range_2_end),
+    ));
+  }
+
+  Ok(
+    vec
+      .iter()
+      .fold(HashMap::new(), |mut acc, (name, range_1, range_2)| {
+        acc.insert(name, (range_1.clone(), range_2.clone()));
+        acc
+      }),
+  )
+}
+
+fn parse_number(ix: usize, line: &str, m: Match) -> Result<u32, AocError> {
+  u32::from_str(m.as_str())
+    .map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(m.start()))
+}
+
+fn parse_ticket(ix: usize, line: &str) -> Result<Vec<u32>, AocError> {
+  let mut column = 0;
+  let mut values: Vec<u32> = vec![];
+
+  for v in line.split(',') {
+    values.push(
+      u32::from_str(v).map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(column))?,
+    );
+    column += v.len() + 1;
+  }
+
+  Ok(values)
+}
+
+// every ticket has to have as many fields as the first
+fn parse_tickets(data: Input) -> Result<Vec<Vec<u32>>, AocError> {
+  let mut tickets: Vec<Vec<u32>> = vec![];
+
+  for (ix, line) in data.lines().iter().enumerate() {
+    let ix = data.offset() + ix;
+    let ticket = parse_ticket(ix, line)?;
+    if let Some(first) = tickets.first() {
+      if ticket.len() != first.len() {
+        return Err(AocError::parse(
+          ix,
+          line,
+          format!("expected {} fields, found {}", first.len(), ticket.len()),
+        ));
+      }
+    }
+    tickets.push(ticket);
+  }
+
+  Ok(tickets)
+}
+
+fn retain_valid_tickets(tickets: &mut Vec<Vec<u32>>, rules: &Rules) {
+  let valid_ranges: Vec<RangeInclusive<u32>> = valid_ranges(rules);
+  tickets.retain(|t| t.iter().all(|v| valid_ranges.iter().any(|r| r.contains(v))));
+}
+
+type Sections<'a> = (Input<'a>, Input<'a>, Input<'a>);
+
+fn split_into_sections<'a>(data: &'a [&'a str]) -> Result<Sections<'a>, AocError> {
+  let sections: Vec<Section> = Input::new(data).sections().collect();
+  if sections.len() < 3 {
+    return Err(AocError::no_solution(
+      "expected rules, your ticket and nearby tickets separated by blank lines",
+    ));
+  }
+
+  expect_header(data, &sections[1], "your ticket")?;
+  expect_header(data, &sections[2], "nearby tickets")?;
+
+  Ok((sections[0].body, sections[1].body, sections[2].body))
+}
+
+fn expect_header(data: &[&str], section: &Section, header: &str) -> Result<(), AocError> {
+  if section.header == Some(header) {
+    Ok(())
+  } else {
+    Err(AocError::parse(
+      section.offset(),
+      data[section.offset()],
+      format!("expected \"{}:\"", header),
+    ))
+  }
+}
+
+fn valid_ranges(rules: &Rules) -> Vec<RangeInclusive<u32>> {
+  rules
+    .values()
+    .flat_map(|(r1, r2)| vec![r1.clone(), r2.clone()])
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use maplit::hashmap;
+  use std::iter::FromIterator;
+
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "part-1" => include_str!("../../../data/2020/examples/day-16/part-1.txt"),
+      _ => include_str!("../../../data/2020/examples/day-16/part-2.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = example("part-1");
+    assert_eq!(Day16::run_part_1(&data), Ok(Answer::Integer(71)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = example("part-2");
+    assert_eq!(Day16::run_part_2(&data), Ok(Answer::Integer(143)));
+
+    // more fields than the 20 in the puzzle input, and only the last one is a departure field
+    let names: Vec<String> = (0..21)
+      .map(|n| match n {
+        20 => "departure: 20-20 or 99-99".to_string(),
+        _ => format!("field {}: {}-{} or 99-99", n, n, n),
+      })
+      .collect();
+    let ticket = (0..21).map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    let mut data: Vec<&str> = names.iter().map(String::as_str).collect();
+    data.extend(["", "your ticket:", &ticket, "", "nearby tickets:", &ticket].iter());
+    assert_eq!(Day16::run_part_2(&data), Ok(Answer::Integer(20)));
+  }
+
+  #[test]
+  fn test_parse_rules() {
+    assert_eq!(
+      parse_rules(Input::new(&[
+        "class: 1-3 or 5-7",
+        "row: 6-11 or 33-44",
+        "seat: 13-40 or 45-50"
+      ])),
+      Ok(hashmap! {
+        "class" => ((1..=3), 5..=7),
+        "row" => ((6..=11), (33..=44)),
+        "seat" => ((13..=40), (45..=50)),
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_ticket() {
+    assert_eq!(parse_ticket(0, "7,1,14"), Ok(vec![7, 1, 14]));
+    assert_eq!(parse_ticket(0, "7,3,47"), Ok(vec![7, 3, 47]));
+    assert_eq!(parse_ticket(0, "40,4,50"), Ok(vec![40, 4, 50]));
+    assert_eq!(parse_ticket(0, "55,2,20"), Ok(vec![55, 2, 20]));
+    assert_eq!(parse_ticket(0, "38,6,12"), Ok(vec![38, 6, 12]));
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let data = [
+      "class: 1-3 or 5-7",
+      "",
+      "your ticket:",
+      "7,1,14",
+      "",
+      "nearby tickets:",
+      "7,3,47",
+      "40,x,50",
+    ];
+    assert_eq!(
+      Day16::parse(&Input::new(&data)).err(),
+      Some(AocError::parse(7, "40,x,50", "invalid digit found in string").at_column(3))
+    );
+    assert_eq!(
+      parse_tickets(Input::new(&["7,3,47", "40,4,50", "55,2"])),
+      Err(AocError::parse(2, "55,2", "expected 3 fields, found 2"))
+    );
+    assert_eq!(
+      parse_rules(Input::new(&["class 1-3 or 5-7"])),
+      Err(AocError::parse(
+        0,
+        "class 1-3 or 5-7",
+        "expected \"<name>: <a>-<b> or <c>-<d>\""
+      ))
+    );
+    assert_eq!(
+      split_into_sections(&[
+        "class: 1-3 or 5-7",
+        "",
+        "my ticket:",
+        "7,1,14",
+        "",
+        "nearby tickets:",
+        "7,3,47"
+      ]),
+      Err(AocError::parse(
+        2,
+        "my ticket:",
+        "expected \"your ticket:\""
+      ))
+    );
+  }
+
+  #[test]
+  fn test_retain_valid_tickets() {
+    let rules = hashmap! {
+      "class" => (1..=3, 5..=7),
+      "row" => (6..=11, 33..=44),
+      "seat" => (13..=40, 45..=50),
+    };
+    let mut tickets = vec![
+      vec![7, 3, 47],
+      vec![40, 4, 50],
+      vec![55, 2, 20],
+      vec![38, 6, 12],
+    ];
+    retain_valid_tickets(&mut tickets, &rules);
+    assert_eq!(tickets, vec![vec![7, 3, 47]]);
+  }
+
+  #[test]
+  fn test_split_into_sections() {
+    let data = example("part-1");
+    let (rules, my_ticket, nearby_tickets) = split_into_sections(&data).unwrap();
+    assert_eq!(rules.lines().first().cloned().unwrap(), "class: 1-3 or 5-7");
+    assert_eq!(my_ticket.lines().first().cloned().unwrap(), "7,1,14");
+    assert_eq!(nearby_tickets.lines().first().cloned().unwrap(), "7,3,47");
+    assert_eq!(nearby_tickets.offset(), 8);
+  }
+
+  #[test]
+  fn test_valid_ranges() {
+    let rules = hashmap! {
+      "class" => (1..=3, 5..=7),
+      "row" => (6..=11, 33..=44),
+      "seat" => (13..=40, 45..=50),
+    };
+    let valid_set: HashSet<RangeInclusive<u32>> =
+      HashSet::from_iter(valid_ranges(&rules).iter().cloned());
+    assert_eq!(
+      valid_set,
+      HashSet::from_iter(
+        [1..=3, 5..=7, 6..=11, 33..=44, 13..=40, 45..=50]
+          .iter()
+          .cloned()
+      )
+    )
+  }
+}
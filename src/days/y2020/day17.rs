@@ -1,30 +1,11 @@
-use common::{Day, Part};
+use crate::grid::Grid;
+use crate::{Answer, AocError};
 use itertools::Itertools;
 use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::iter::FromIterator;
 use std::ops::RangeInclusive;
 
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-17-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(319, day.part_1.result);
-    assert_eq!(2324, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-17-input.txt");
-    std::process::exit(1);
-  }
-}
-
 type Point = (i64, i64, i64, i64);
 type Extents = (
   RangeInclusive<i64>,
@@ -33,26 +14,44 @@ type Extents = (
   RangeInclusive<i64>,
 );
 
-pub fn part_1(data: &[&str]) -> u64 {
+#[derive(PartialEq)]
+enum Cube {
+  Active,
+  Inactive,
+}
+
+impl TryFrom<char> for Cube {
+  type Error = ();
+
+  fn try_from(c: char) -> Result<Cube, ()> {
+    match c {
+      '#' => Ok(Cube::Active),
+      '.' => Ok(Cube::Inactive),
+      _ => Err(()),
+    }
+  }
+}
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
   let mut grid: HashSet<Point> = HashSet::new();
-  initialize_space(&data, &mut grid);
+  initialize_space(data, &mut grid)?;
 
   for _ in 0..6 {
     step_3d(&mut grid);
   }
 
-  grid.len() as u64
+  Ok(Answer::from(grid.len()))
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
   let mut grid: HashSet<Point> = HashSet::new();
-  initialize_space(&data, &mut grid);
+  initialize_space(data, &mut grid)?;
 
   for _ in 0..6 {
     step_4d(&mut grid);
   }
 
-  grid.len() as u64
+  Ok(Answer::from(grid.len()))
 }
 
 fn count_neighbors_3d(grid: &HashSet<Point>, cell: &Point) -> u8 {
@@ -95,48 +94,49 @@ fn count_neighbors_4d(grid: &HashSet<Point>, cell: &Point) -> u8 {
   count
 }
 
-fn extents(grid: &HashSet<Point>) -> Extents {
+// None when nothing is active
+fn extents(grid: &HashSet<Point>) -> Option<Extents> {
   let ((min_x, _, _, _), (max_x, _, _, _)) = grid
     .iter()
     .minmax_by(|(x1, _, _, _), (x2, _, _, _)| x1.cmp(x2))
-    .into_option()
-    .unwrap();
+    .into_option()?;
   let ((_, min_y, _, _), (_, max_y, _, _)) = grid
     .iter()
     .minmax_by(|(_, y1, _, _), (_, y2, _, _)| y1.cmp(y2))
-    .into_option()
-    .unwrap();
+    .into_option()?;
   let ((_, _, min_z, _), (_, _, max_z, _)) = grid
     .iter()
     .minmax_by(|(_, _, z1, _), (_, _, z2, _)| z1.cmp(z2))
-    .into_option()
-    .unwrap();
+    .into_option()?;
   let ((_, _, _, min_w), (_, _, _, max_w)) = grid
     .iter()
     .minmax_by(|(_, _, _, w1), (_, _, _, w2)| w1.cmp(w2))
-    .into_option()
-    .unwrap();
-  (
+    .into_option()?;
+  Some((
     *min_x..=*max_x,
     *min_y..=*max_y,
     *min_z..=*max_z,
     *min_w..=*max_w,
-  )
+  ))
 }
 
-fn initialize_space(data: &[&str], grid: &mut HashSet<Point>) {
-  for (y, row) in data.iter().enumerate() {
-    for (x, col) in row.chars().enumerate() {
-      if col == '#' {
-        grid.insert((x as i64, y as i64, 0, 0));
-      }
+fn initialize_space(data: &[&str], grid: &mut HashSet<Point>) -> Result<(), AocError> {
+  for ((x, y), cube) in Grid::<Cube>::parse(data)?.iter() {
+    if *cube == Cube::Active {
+      grid.insert((x as i64, y as i64, 0, 0));
     }
   }
+
+  Ok(())
 }
 
 fn step_3d(grid: &mut HashSet<Point>) {
   let previous: HashSet<Point> = HashSet::from_iter(grid.iter().copied());
-  let (range_x, range_y, range_z, _range_w) = extents(&previous);
+  let (range_x, range_y, range_z, _range_w) = match extents(&previous) {
+    Some(extents) => extents,
+    // nothing is active, so nothing can become active
+    None => return,
+  };
 
   for x in range_x.start() - 1..=range_x.end() + 1 {
     for y in range_y.start() - 1..=range_y.end() + 1 {
@@ -156,7 +156,11 @@ fn step_3d(grid: &mut HashSet<Point>) {
 
 fn step_4d(grid: &mut HashSet<Point>) {
   let previous: HashSet<Point> = HashSet::from_iter(grid.iter().copied());
-  let (range_x, range_y, range_z, range_w) = extents(&previous);
+  let (range_x, range_y, range_z, range_w) = match extents(&previous) {
+    Some(extents) => extents,
+    // nothing is active, so nothing can become active
+    None => return,
+  };
 
   for x in range_x.start() - 1..=range_x.end() + 1 {
     for y in range_y.start() - 1..=range_y.end() + 1 {
@@ -180,16 +184,22 @@ fn step_4d(grid: &mut HashSet<Point>) {
 mod tests {
   use super::*;
 
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-17/example.txt")
+      .lines()
+      .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec![".#.", "..#", "###"];
-    assert_eq!(part_1(&data), 112);
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(112)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec![".#.", "..#", "###"];
-    assert_eq!(part_2(&data), 848);
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(848)));
   }
 
   #[test]
@@ -197,8 +207,8 @@ mod tests {
     let mut grid: HashSet<Point> = HashSet::new();
     assert_eq!(count_neighbors_3d(&grid, &(0, 0, 0, 0)), 0);
 
-    let data = vec![".#.", "..#", "###"];
-    initialize_space(&data, &mut grid);
+    let data = get_test_data();
+    initialize_space(&data, &mut grid).unwrap();
     assert_eq!(count_neighbors_3d(&grid, &(0, 0, 0, 0)), 1);
     assert_eq!(count_neighbors_3d(&grid, &(1, 2, 0, 0)), 3);
   }
@@ -208,42 +218,56 @@ mod tests {
     let mut grid: HashSet<Point> = HashSet::new();
     assert_eq!(count_neighbors_4d(&grid, &(0, 0, 0, 0)), 0);
 
-    let data = vec![".#.", "..#", "###"];
-    initialize_space(&data, &mut grid);
+    let data = get_test_data();
+    initialize_space(&data, &mut grid).unwrap();
     assert_eq!(count_neighbors_4d(&grid, &(0, 0, 0, 0)), 1);
     assert_eq!(count_neighbors_4d(&grid, &(1, 2, 0, 0)), 3);
   }
 
   #[test]
   fn test_extents() {
-    let data = vec![".#.", "..##", "###"];
+    let data = vec![".#..", "..##", "###."];
     let mut grid: HashSet<Point> = HashSet::new();
-    initialize_space(&data, &mut grid);
-    let (range_x, range_y, range_z, range_w) = extents(&grid);
+    initialize_space(&data, &mut grid).unwrap();
+    let (range_x, range_y, range_z, range_w) = extents(&grid).unwrap();
     assert_eq!(range_x, (0..=3));
     assert_eq!(range_y, (0..=2));
     assert_eq!(range_z, (0..=0));
     assert_eq!(range_w, (0..=0));
+
+    assert_eq!(extents(&HashSet::new()), None);
+  }
+
+  #[test]
+  fn test_nothing_active() {
+    let data = vec!["...", "..."];
+    assert_eq!(part_1(&data), Ok(Answer::Integer(0)));
+    assert_eq!(part_2(&data), Ok(Answer::Integer(0)));
   }
 
   #[test]
   fn test_initialize_space() {
-    let data = vec![".#.", "..#", "###"];
+    let data = get_test_data();
     let mut grid: HashSet<Point> = HashSet::new();
-    initialize_space(&data, &mut grid);
+    initialize_space(&data, &mut grid).unwrap();
     assert_eq!(grid.len(), 5);
     assert!(grid.contains(&(1, 0, 0, 0)));
     assert!(grid.contains(&(2, 1, 0, 0)));
     assert!(grid.contains(&(0, 2, 0, 0)));
     assert!(grid.contains(&(1, 2, 0, 0)));
     assert!(grid.contains(&(2, 2, 0, 0)));
+
+    assert_eq!(
+      initialize_space(&[".#.", ".#"], &mut grid),
+      Err(AocError::parse(1, ".#", "expected 3 columns, found 2"))
+    );
   }
 
   #[test]
   fn test_step_3d() {
-    let data = vec![".#.", "..#", "###"];
+    let data = get_test_data();
     let mut grid: HashSet<Point> = HashSet::new();
-    initialize_space(&data, &mut grid);
+    initialize_space(&data, &mut grid).unwrap();
 
     step_3d(&mut grid);
     // println!("{:?}", grid);
@@ -252,9 +276,9 @@ mod tests {
 
   #[test]
   fn test_step_4d() {
-    let data = vec![".#.", "..#", "###"];
+    let data = get_test_data();
     let mut grid: HashSet<Point> = HashSet::new();
-    initialize_space(&data, &mut grid);
+    initialize_space(&data, &mut grid).unwrap();
 
     step_4d(&mut grid);
     // println!("{:?}", grid);
@@ -1,31 +1,10 @@
-use common::{Day, Part};
+use crate::{Answer, AocError};
 use lexer::*;
 use maplit::hashmap;
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::iter::FromIterator;
 
-pub fn main() {
-  let mut data: Vec<String> = vec![];
-
-  if common::load_data("data/day-18-input.txt", &mut data).is_ok() {
-    let part_1 = Part::new(part_1);
-    let part_2 = Part::new(part_2);
-
-    let mut day = Day::new(part_1, part_2);
-
-    day.run(&data);
-
-    assert_eq!(1451467526514, day.part_1.result);
-    assert_eq!(224973686321527, day.part_2.result);
-
-    println!("{}", day.to_string());
-  } else {
-    eprintln!("cannot open data/day-18-input.txt");
-    std::process::exit(1);
-  }
-}
-
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum TokenValue {
   Number(u64),
@@ -119,10 +98,16 @@ impl Reader<Token, TokenError> for NumberReader {
             }
           }
 
-          ReaderResult::Some(Token::new(
-            TokenMeta::new_state_meta(current, next),
-            TokenValue::Number(string.parse().unwrap()),
-          ))
+          match string.parse() {
+            Ok(number) => ReaderResult::Some(Token::new(
+              TokenMeta::new_state_meta(current, next),
+              TokenValue::Number(number),
+            )),
+            Err(_) => ReaderResult::Err(TokenError::new(
+              TokenMeta::new_state_meta(current, next),
+              "number is too large",
+            )),
+          }
         } else {
           ReaderResult::None
         }
@@ -173,7 +158,7 @@ impl Reader<Token, TokenError> for OpReader {
   }
 }
 
-pub fn part_1(data: &[&str]) -> u64 {
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
   let precedence_levels = hashmap! {
     TokenValue::Plus => 2,
     TokenValue::Times => 2,
@@ -181,14 +166,10 @@ pub fn part_1(data: &[&str]) -> u64 {
     TokenValue::CloseParen => 1
   };
 
-  data
-    .iter()
-    .map(|exp| parse(exp))
-    .map(|tokens| evaluate_infix(&tokens, &precedence_levels))
-    .sum::<u64>() as u64
+  evaluate_all(data, &precedence_levels).map(Answer::from)
 }
 
-pub fn part_2(data: &[&str]) -> u64 {
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
   let precedence_levels = hashmap! {
     TokenValue::Plus => 3,
     TokenValue::Times => 2,
@@ -196,48 +177,67 @@ pub fn part_2(data: &[&str]) -> u64 {
     TokenValue::CloseParen => 1
   };
 
-  data
-    .iter()
-    .map(|exp| parse(exp))
-    .map(|tokens| evaluate_infix(&tokens, &precedence_levels))
-    .sum::<u64>() as u64
+  evaluate_all(data, &precedence_levels).map(Answer::from)
+}
+
+fn evaluate_all(
+  data: &[&str],
+  precedence_levels: &HashMap<TokenValue, u8>,
+) -> Result<u64, AocError> {
+  let mut sum: u64 = 0;
+
+  for (ix, exp) in data.iter().enumerate() {
+    let tokens = parse(exp).map_err(|e| e.offset_lines(ix))?;
+    let value = evaluate_infix(&tokens, precedence_levels)
+      .ok_or_else(|| AocError::parse(ix, exp, "malformed expression"))?;
+    sum = sum
+      .checked_add(value)
+      .ok_or_else(|| AocError::parse(ix, exp, "sum overflows u64"))?;
+  }
+
+  Ok(sum)
 }
 
+// None if the expression is malformed or overflows
 fn evaluate_infix(
   infix: &VecDeque<TokenValue>,
   precedence_levels: &HashMap<TokenValue, u8>,
-) -> u64 {
-  evaluate_postfix(&infix_to_postfix(infix, precedence_levels))
+) -> Option<u64> {
+  evaluate_postfix(&infix_to_postfix(infix, precedence_levels)?)
 }
 
-fn evaluate_postfix(postfix: &VecDeque<TokenValue>) -> u64 {
+fn evaluate_postfix(postfix: &VecDeque<TokenValue>) -> Option<u64> {
   let mut stack: VecDeque<u64> = VecDeque::new();
 
   for token in postfix.iter() {
     match token {
       TokenValue::Number(number) => stack.push_front(*number),
       TokenValue::Plus => {
-        let x = stack.pop_front().unwrap();
-        let y = stack.pop_front().unwrap();
-        stack.push_front(x + y);
+        let x = stack.pop_front()?;
+        let y = stack.pop_front()?;
+        stack.push_front(x.checked_add(y)?);
       }
       TokenValue::Times => {
-        let x = stack.pop_front().unwrap();
-        let y = stack.pop_front().unwrap();
-        stack.push_front(x * y);
+        let x = stack.pop_front()?;
+        let y = stack.pop_front()?;
+        stack.push_front(x.checked_mul(y)?);
       }
       TokenValue::OpenParen => {}
       TokenValue::CloseParen => {}
     }
   }
 
-  stack.pop_front().unwrap()
+  match (stack.pop_front(), stack.is_empty()) {
+    (Some(value), true) => Some(value),
+    _ => None,
+  }
 }
 
+// None if the parentheses are unbalanced
 fn infix_to_postfix(
   infix: &VecDeque<TokenValue>,
   precedence_levels: &HashMap<TokenValue, u8>,
-) -> VecDeque<TokenValue> {
+) -> Option<VecDeque<TokenValue>> {
   let mut postfix: VecDeque<TokenValue> = VecDeque::with_capacity(infix.capacity());
   let mut stack: VecDeque<TokenValue> = VecDeque::new();
 
@@ -247,7 +247,7 @@ fn infix_to_postfix(
       TokenValue::OpenParen => stack.push_front(TokenValue::OpenParen),
       TokenValue::CloseParen => {
         while stack.front() != Some(&TokenValue::OpenParen) {
-          let op = stack.pop_front().unwrap();
+          let op = stack.pop_front()?;
           postfix.push_back(op);
         }
         stack.pop_front(); // discard the open paren
@@ -274,53 +274,61 @@ fn infix_to_postfix(
   }
 
   // drain anything remaining on the stack
-  while !stack.is_empty() {
-    postfix.push_back(stack.pop_front().unwrap());
+  while let Some(op) = stack.pop_front() {
+    if op == TokenValue::OpenParen {
+      return None;
+    }
+    postfix.push_back(op);
   }
 
-  postfix
+  Some(postfix)
 }
 
-fn parse(expression: &str) -> VecDeque<TokenValue> {
+// errors are reported as if the expression were the first line of input
+fn parse(expression: &str) -> Result<VecDeque<TokenValue>, AocError> {
+  // the lexer silently stops at a character none of the readers accept, so catch those first
+  if let Some((column, ch)) = expression
+    .char_indices()
+    .find(|(_, ch)| !(ch.is_whitespace() || ch.is_numeric() || "+*()".contains(*ch)))
+  {
+    return Err(
+      AocError::parse(0, expression, format!("unexpected character '{}'", ch)).at_column(column),
+    );
+  }
+
   let readers = ReadersBuilder::new()
     .add(WhitespaceReader)
     .add(NumberReader)
     .add(OpReader)
     .build();
   let lexer = readers.lexer(expression.chars());
-  let tokens: Vec<Token> = lexer.map(Result::unwrap).collect();
+  let tokens: Vec<Token> = lexer.collect::<Result<_, _>>().map_err(|e: TokenError| {
+    AocError::parse(0, expression, *e.error()).at_column(e.meta().col_start() as usize)
+  })?;
   let token_values: Vec<TokenValue> = tokens.iter().map(lexer::Token::value).cloned().collect();
-  VecDeque::from_iter(token_values.into_iter())
+  Ok(VecDeque::from_iter(token_values))
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  fn get_test_data() -> Vec<&'static str> {
+    include_str!("../../../data/2020/examples/day-18/example.txt")
+      .lines()
+      .collect()
+  }
+
   #[test]
   fn test_part_1() {
-    let data = vec![
-      "1 + 2 * 3 + 4 * 5 + 6",
-      "1 + (2 * 3) + (4 * (5 + 6))",
-      "2 * 3 + (4 * 5)",
-      "5 + (8 * 3 + 9 + 3 * 4 * 3)",
-      "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))",
-      "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
-    ];
-    assert_eq!(part_1(&data), 26457);
+    let data = get_test_data();
+    assert_eq!(part_1(&data), Ok(Answer::Integer(26457)));
   }
 
   #[test]
   fn test_part_2() {
-    let data = vec![
-      "1 + 2 * 3 + 4 * 5 + 6",
-      "1 + (2 * 3) + (4 * (5 + 6))",
-      "2 * 3 + (4 * 5)",
-      "5 + (8 * 3 + 9 + 3 * 4 * 3)",
-      "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))",
-      "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
-    ];
-    assert_eq!(part_2(&data), 694173);
+    let data = get_test_data();
+    assert_eq!(part_2(&data), Ok(Answer::Integer(694173)));
   }
 
   #[test]
@@ -349,7 +357,7 @@ mod tests {
         ]),
         &precedence_levels
       ),
-      71
+      Some(71)
     );
     assert_eq!(
       evaluate_infix(
@@ -374,7 +382,7 @@ mod tests {
         ]),
         &precedence_levels
       ),
-      51
+      Some(51)
     );
   }
   #[test]
@@ -401,7 +409,7 @@ mod tests {
     ]);
     assert_eq!(
       infix_to_postfix(&data, &precedence_levels),
-      VecDeque::from(vec![
+      Some(VecDeque::from(vec![
         TokenValue::Number(1),
         TokenValue::Number(2),
         TokenValue::Plus,
@@ -413,7 +421,7 @@ mod tests {
         TokenValue::Times,
         TokenValue::Number(6),
         TokenValue::Plus,
-      ])
+      ]))
     );
   }
 
@@ -447,7 +455,7 @@ mod tests {
     ]);
     assert_eq!(
       infix_to_postfix(&data, &precedence_levels),
-      VecDeque::from(vec![
+      Some(VecDeque::from(vec![
         TokenValue::Number(1),
         TokenValue::Number(2),
         TokenValue::Number(3),
@@ -459,7 +467,7 @@ mod tests {
         TokenValue::Plus,
         TokenValue::Times,
         TokenValue::Plus,
-      ])
+      ]))
     );
   }
 
@@ -487,7 +495,7 @@ mod tests {
     ]);
     assert_eq!(
       infix_to_postfix(&data, &precedence_levels),
-      VecDeque::from(vec![
+      Some(VecDeque::from(vec![
         TokenValue::Number(1),
         TokenValue::Number(2),
         TokenValue::Plus,
@@ -499,7 +507,7 @@ mod tests {
         TokenValue::Number(6),
         TokenValue::Plus,
         TokenValue::Times,
-      ])
+      ]))
     );
   }
 
@@ -508,7 +516,7 @@ mod tests {
     let expression = "1 + (2 * 3) + (4 * (5 + 6))";
     assert_eq!(
       parse(expression),
-      VecDeque::from(vec![
+      Ok(VecDeque::from(vec![
         TokenValue::Number(1),
         TokenValue::Plus,
         TokenValue::OpenParen,
@@ -526,7 +534,27 @@ mod tests {
         TokenValue::Number(6),
         TokenValue::CloseParen,
         TokenValue::CloseParen,
-      ])
+      ]))
+    );
+  }
+
+  #[test]
+  fn test_malformed_expressions() {
+    assert_eq!(
+      part_1(&["1 + 2", "3 - 4"]),
+      Err(AocError::parse(1, "3 - 4", "unexpected character '-'").at_column(2))
+    );
+    assert_eq!(
+      part_1(&["1 + (2 * 3"]),
+      Err(AocError::parse(0, "1 + (2 * 3", "malformed expression"))
+    );
+    assert_eq!(
+      part_2(&["1 + 2)"]),
+      Err(AocError::parse(0, "1 + 2)", "malformed expression"))
+    );
+    assert_eq!(
+      part_2(&["1 + * 2"]),
+      Err(AocError::parse(0, "1 + * 2", "malformed expression"))
     );
   }
 }
@@ -0,0 +1,328 @@
+// many thanks for u/karjonas on Reddit, without whose solution I likely would still
+// be at the bottom of a rabbit hole
+
+use crate::{Answer, AocError, Input};
+use regex::{Match, Regex};
+use std::collections::HashMap;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub fn part_1(data: &[&str]) -> Result<Answer, AocError> {
+  let (rule_lines, messages) = split_into_sections(data)?;
+  let rules = load_rules(rule_lines)?;
+  check_rules(&rules)?;
+
+  Ok(Answer::from(valid_messages(&rules, messages)))
+}
+
+pub fn part_2(data: &[&str]) -> Result<Answer, AocError> {
+  let (rule_lines, messages) = split_into_sections(data)?;
+  let mut rules = load_rules(rule_lines)?;
+  fix_rules(&mut rules);
+  check_rules(&rules)?;
+
+  Ok(Answer::from(valid_messages(&rules, messages)))
+}
+
+#[derive(Debug, PartialEq)]
+enum Rule {
+  A,
+  B,
+  And(Vec<u8>),
+  Or(Vec<u8>, Vec<u8>),
+}
+
+fn load_rules<'a>(data: &'a [&'a str]) -> Result<HashMap<u8, Rule>, AocError> {
+  let re_terminal_a = Regex::new(r#"^(\d+): "a"$"#).unwrap();
+  let re_terminal_b = Regex::new(r#"^(\d+): "b"$"#).unwrap();
+  let re_and = Regex::new(r"^(\d+): (\d+( \d+)*)$").unwrap();
+  let re_or = Regex::new(r"^(\d+): (\d+( \d+)*) \| (\d+( \d+)*)$").unwrap();
+
+  let mut rules: HashMap<u8, Rule> = HashMap::new();
+
+  for (ix, line) in data.iter().enumerate() {
+    // re_or *must* be checked before re_and!
+
+    if re_terminal_a.is_match(line) {
+      let captures = re_terminal_a.captures(line).unwrap();
+      let rule_id = parse_rule_id(ix, line, captures.get(1).unwrap())?;
+      rules.insert(rule_id, Rule::A);
+    } else if re_terminal_b.is_match(line) {
+      let captures = re_terminal_b.captures(line).unwrap();
+      let rule_id = parse_rule_id(ix, line, captures.get(1).unwrap())?;
+      rules.insert(rule_id, Rule::B);
+    } else if re_or.is_match(line) {
+      let captures = re_or.captures(line).unwrap();
+      let rule_id = parse_rule_id(ix, line, captures.get(1).unwrap())?;
+      let lhs = parse_dependent_ids(ix, line, captures.get(2).unwrap())?;
+      let rhs = parse_dependent_ids(ix, line, captures.get(4).unwrap())?;
+      rules.insert(rule_id, Rule::Or(lhs, rhs));
+    } else if re_and.is_match(line) {
+      let captures = re_and.captures(line).unwrap();
+      let rule_id = parse_rule_id(ix, line, captures.get(1).unwrap())?;
+      rules.insert(
+        rule_id,
+        Rule::And(parse_dependent_ids(ix, line, captures.get(2).unwrap())?),
+      );
+    } else {
+      return Err(AocError::parse(ix, line, "don't know how to parse rule"));
+    }
+  }
+
+  Ok(rules)
+}
+
+fn parse_rule_id(ix: usize, line: &str, m: Match) -> Result<u8, AocError> {
+  u8::from_str(m.as_str())
+    .map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(m.start()))
+}
+
+fn parse_dependent_ids(ix: usize, line: &str, m: Match) -> Result<Vec<u8>, AocError> {
+  extract_dependent_ids(m.as_str())
+    .map_err(|e| AocError::parse(ix, line, e.to_string()).at_column(m.start()))
+}
+
+fn extract_dependent_ids(ids: &str) -> Result<Vec<u8>, ParseIntError> {
+  Regex::new(r"\s+")
+    .unwrap()
+    .split(ids)
+    .map(u8::from_str)
+    .collect()
+}
+
+// every rule referenced from another rule (and rule 0 itself) has to be defined
+fn check_rules(rules: &HashMap<u8, Rule>) -> Result<(), AocError> {
+  let mut referenced: Vec<u8> = vec![0];
+  for rule in rules.values() {
+    match rule {
+      Rule::A | Rule::B => {}
+      Rule::And(ids) => referenced.extend(ids),
+      Rule::Or(lhs, rhs) => {
+        referenced.extend(lhs);
+        referenced.extend(rhs);
+      }
+    }
+  }
+
+  match referenced.iter().find(|id| !rules.contains_key(id)) {
+    Some(id) => Err(AocError::no_solution(format!("rule {} is not defined", id))),
+    None => Ok(()),
+  }
+}
+
+fn fix_rules(rules: &mut HashMap<u8, Rule>) {
+  rules.insert(8, Rule::Or(vec![42], vec![42, 8]));
+  rules.insert(11, Rule::Or(vec![42, 31], vec![42, 11, 31]));
+}
+
+fn get_rule_by_id(rules: &HashMap<u8, Rule>, id: u8) -> &Rule {
+  if let Some(rule) = rules.get(&id) {
+    rule
+  } else {
+    panic!("tried to get id = {} from rules = {:?}", id, rules);
+  }
+}
+
+fn resolve(rules: &HashMap<u8, Rule>, rule_id: u8, text: &Vec<char>, ix: usize) -> Vec<usize> {
+  if ix >= text.len() {
+    return vec![];
+  }
+
+  let mut matches: Vec<usize> = vec![];
+
+  match get_rule_by_id(rules, rule_id) {
+    Rule::A => {
+      if text[ix] == 'a' {
+        return [ix + 1].to_vec();
+      } else {
+        return vec![];
+      }
+    }
+    Rule::B => {
+      if text[ix] == 'b' {
+        return [ix + 1].to_vec();
+      } else {
+        return vec![];
+      }
+    }
+    Rule::And(subrule_ids) => {
+      matches = resolve_and(rules, subrule_ids, text, ix);
+    }
+    Rule::Or(lhs, rhs) => {
+      matches.append(&mut resolve_and(rules, lhs, text, ix));
+      matches.append(&mut resolve_and(rules, rhs, text, ix));
+    }
+  }
+
+  matches
+}
+
+fn resolve_and(
+  rules: &HashMap<u8, Rule>,
+  rule_ids: &[u8],
+  text: &Vec<char>,
+  ix: usize,
+) -> Vec<usize> {
+  let mut ixs = [ix].to_vec();
+
+  for rule_id in rule_ids {
+    let mut new_ixs = vec![];
+    for jx in &ixs {
+      new_ixs.append(&mut resolve(rules, *rule_id, text, *jx));
+    }
+
+    ixs = new_ixs;
+
+    if ixs.is_empty() {
+      break;
+    }
+  }
+
+  ixs
+}
+
+fn split_into_sections<'a>(
+  data: &'a [&'a str],
+) -> Result<(&'a [&'a str], &'a [&'a str]), AocError> {
+  match Input::new(data).paragraphs().collect::<Vec<Input>>()[..] {
+    [rules, messages, ..] => Ok((rules.lines(), messages.lines())),
+    _ => Err(AocError::no_solution(
+      "expected rules and messages separated by a blank line",
+    )),
+  }
+}
+
+fn valid_messages(rules: &HashMap<u8, Rule>, messages: &[&str]) -> u64 {
+  let mut count = 0;
+  for message in messages {
+    if resolve(rules, 0, &message.chars().collect(), 0).contains(&message.len()) {
+      count += 1;
+    }
+  }
+
+  count
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn example(name: &str) -> Vec<&'static str> {
+    match name {
+      "rules" => include_str!("../../../data/2020/examples/day-19/rules.txt"),
+      _ => include_str!("../../../data/2020/examples/day-19/loops.txt"),
+    }
+    .lines()
+    .collect()
+  }
+
+  #[test]
+  fn test_part_1() {
+    let data = example("rules");
+    assert_eq!(part_1(&data), Ok(Answer::Integer(2)));
+  }
+
+  #[test]
+  fn test_part_2() {
+    let data = example("loops");
+    assert_eq!(part_1(&data), Ok(Answer::Integer(3)));
+    assert_eq!(part_2(&data), Ok(Answer::Integer(12)));
+  }
+
+  #[test]
+  fn test_extract_dependent_ids() {
+    assert_eq!(extract_dependent_ids("4 1 5"), Ok(vec![4, 1, 5]));
+  }
+
+  #[test]
+  fn test_fix_rules() {
+    let data = vec![
+      "0: 4 1 5",
+      "1: 2 3 | 3 2",
+      "2: 4 4 | 5 5",
+      "3: 4 5 | 5 4",
+      "4: \"a\"",
+      "5: \"b\"",
+      "8: 42",
+      "11: 42 31",
+    ];
+    let mut rules = load_rules(&data).unwrap();
+    assert_eq!(*rules.get(&8).unwrap(), Rule::And(vec![42]));
+    assert_eq!(*rules.get(&11).unwrap(), Rule::And(vec![42, 31]));
+
+    fix_rules(&mut rules);
+    assert_eq!(*rules.get(&8).unwrap(), Rule::Or(vec![42], vec![42, 8]));
+    assert_eq!(
+      *rules.get(&11).unwrap(),
+      Rule::Or(vec![42, 31], vec![42, 11, 31])
+    );
+  }
+
+  #[test]
+  fn test_load_rules() {
+    let data = vec![
+      "0: 4 1 5",
+      "1: 2 3 | 3 2",
+      "2: 4 4 | 5 5",
+      "3: 4 5 | 5 4",
+      "4: \"a\"",
+      "5: \"b\"",
+    ];
+    let rules = load_rules(&data).unwrap();
+    assert_eq!(rules.len(), 6);
+    assert_eq!(*rules.get(&0).unwrap(), Rule::And(vec![4, 1, 5]));
+    assert_eq!(*rules.get(&1).unwrap(), Rule::Or(vec![2, 3], vec![3, 2]));
+    assert_eq!(*rules.get(&4).unwrap(), Rule::A);
+    assert_eq!(*rules.get(&5).unwrap(), Rule::B);
+  }
+
+  #[test]
+  fn test_split_into_sections() {
+    let data = vec![
+      "0: 4 1 5",
+      "1: 2 3 | 3 2",
+      "2: 4 4 | 5 5",
+      "3: 4 5 | 5 4",
+      "4: \"a\"",
+      "5: \"b\"",
+      "",
+      "ababbb",
+      "bababa",
+      "abbbab",
+      "aaabbb",
+      "aaaabbb",
+    ];
+
+    let (rules, messages) = split_into_sections(&data).unwrap();
+    assert_eq!(rules.len(), 6);
+    assert_eq!(messages.len(), 5);
+  }
+
+  #[test]
+  fn test_rule_errors() {
+    assert_eq!(
+      load_rules(&["0: 4 1 5", "1: 2 3 | x"]),
+      Err(AocError::parse(
+        1,
+        "1: 2 3 | x",
+        "don't know how to parse rule"
+      ))
+    );
+    assert_eq!(
+      load_rules(&["0: 4 1 5", "1: 2 3 | 300 2"]),
+      Err(
+        AocError::parse(
+          1,
+          "1: 2 3 | 300 2",
+          "number too large to fit in target type"
+        )
+        .at_column(9)
+      )
+    );
+    assert_eq!(
+      part_1(&["0: 1 2", "1: \"a\"", "", "ab"]),
+      Err(AocError::no_solution("rule 2 is not defined"))
+    );
+    assert!(split_into_sections(&["0: 1 2"]).is_err());
+  }
+}
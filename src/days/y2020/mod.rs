@@ -0,0 +1,140 @@
+use crate::days::Entry;
+use crate::{solve, Solver};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19_2;
+
+pub static REGISTRY: &[Entry] = &[
+  Entry {
+    year: 2020,
+    day: 1,
+    solver: Solver::Parts(day01::part_1, day01::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 2,
+    solver: Solver::Parts(day02::part_1, day02::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 3,
+    solver: Solver::Parts(day03::part_1, day03::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 4,
+    solver: Solver::Parsed(solve::<day04::Day04>),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 5,
+    solver: Solver::Parts(day05::part_1, day05::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 6,
+    solver: Solver::Parts(day06::part_1, day06::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 7,
+    solver: Solver::Parts(day07::part_1, day07::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 8,
+    solver: Solver::Parts(day08::part_1, day08::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 9,
+    solver: Solver::Parts(day09::part_1, day09::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 10,
+    solver: Solver::Parts(day10::part_1, day10::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 11,
+    solver: Solver::Parts(day11::part_1, day11::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 12,
+    solver: Solver::Parts(day12::part_1, day12::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 13,
+    solver: Solver::Parts(day13::part_1, day13::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 14,
+    solver: Solver::Parsed(solve::<day14::Day14>),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 15,
+    solver: Solver::Parts(day15::part_1, day15::part_2),
+    input: Some(day15::INPUT),
+  },
+  Entry {
+    year: 2020,
+    day: 16,
+    solver: Solver::Parsed(solve::<day16::Day16>),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 17,
+    solver: Solver::Parts(day17::part_1, day17::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 18,
+    solver: Solver::Parts(day18::part_1, day18::part_2),
+    input: None,
+  },
+  // the second attempt at day 19; the first only ever solved part 1
+  Entry {
+    year: 2020,
+    day: 19,
+    solver: Solver::Parts(day19_2::part_1, day19_2::part_2),
+    input: None,
+  },
+];
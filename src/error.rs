@@ -0,0 +1,149 @@
+use crate::format_duration;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AocError {
+  // a line of input couldn't be understood; line and column are 1-based
+  Parse {
+    line: usize,
+    column: Option<usize>,
+    text: String,
+    message: String,
+  },
+  // the input was understood, but it doesn't lead to an answer
+  NoSolution(String),
+  // the part was still running when its time ran out, after this long
+  TimedOut(Duration),
+  // the day's settings (see DayId::settings_path()) couldn't be understood
+  Settings(String),
+}
+
+impl AocError {
+  // ix is the 0-based index of the line in the slice passed to the part
+  pub fn parse(ix: usize, text: &str, message: impl Into<String>) -> AocError {
+    AocError::Parse {
+      line: ix + 1,
+      column: None,
+      text: text.to_string(),
+      message: message.into(),
+    }
+  }
+
+  pub fn no_solution(message: impl Into<String>) -> AocError {
+    AocError::NoSolution(message.into())
+  }
+
+  // ix is the 0-based byte offset into the line
+  pub fn at_column(self, ix: usize) -> AocError {
+    match self {
+      AocError::Parse {
+        line,
+        text,
+        message,
+        ..
+      } => AocError::Parse {
+        line,
+        column: Some(ix + 1),
+        text,
+        message,
+      },
+      other => other,
+    }
+  }
+
+  // for errors raised while parsing a section that starts partway through the input
+  pub fn offset_lines(self, lines: usize) -> AocError {
+    match self {
+      AocError::Parse {
+        line,
+        column,
+        text,
+        message,
+      } => AocError::Parse {
+        line: line + lines,
+        column,
+        text,
+        message,
+      },
+      other => other,
+    }
+  }
+}
+
+impl fmt::Display for AocError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      AocError::Parse {
+        line,
+        column: None,
+        text,
+        message,
+      } => write!(f, "line {}: {}\n  {}", line, message, text),
+      AocError::Parse {
+        line,
+        column: Some(column),
+        text,
+        message,
+      } => write!(
+        f,
+        "line {}, column {}: {}\n  {}\n  {:>width$}",
+        line,
+        column,
+        message,
+        text,
+        "^",
+        width = column
+      ),
+      AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+      AocError::TimedOut(elapsed) => write!(f, "timed out after {}", format_duration(*elapsed)),
+      AocError::Settings(message) => write!(f, "bad settings: {}", message),
+    }
+  }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_display() {
+    assert_eq!(
+      AocError::parse(2, "X10", "unexpected command selector: X").to_string(),
+      "line 3: unexpected command selector: X\n  X10"
+    );
+    assert_eq!(
+      AocError::parse(0, "mem[8] = x", "expected a number")
+        .at_column(9)
+        .to_string(),
+      "line 1, column 10: expected a number\n  mem[8] = x\n           ^"
+    );
+    assert_eq!(
+      AocError::no_solution("device did not halt").to_string(),
+      "no solution: device did not halt"
+    );
+    assert_eq!(
+      AocError::TimedOut(Duration::from_millis(1500)).to_string(),
+      "timed out after 1.500 s"
+    );
+    assert_eq!(
+      AocError::Settings("expected a table".to_string()).to_string(),
+      "bad settings: expected a table"
+    );
+  }
+
+  #[test]
+  fn test_offset_lines() {
+    assert_eq!(
+      AocError::parse(0, "7,1,x", "bad ticket").offset_lines(5),
+      AocError::parse(5, "7,1,x", "bad ticket")
+    );
+    assert_eq!(
+      AocError::no_solution("nope").offset_lines(5),
+      AocError::no_solution("nope")
+    );
+  }
+}
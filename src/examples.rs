@@ -0,0 +1,270 @@
+use crate::days;
+use crate::expected::parse_parts;
+use crate::{Day, DayId, InputSource, Part, Status};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// where each year's data lives (see data_dir()), relative to the top of the repository
+pub const DATA: &str = "data";
+
+// an example from a puzzle, kept as <data>/<year>/examples/day-NN/<name>.txt, with the answers it
+// should give in <name>.toml next to it:
+//
+//   part_1 = 37
+//   part_2 = 26
+//
+// either part can be left out, e.g. when the example only applies to one of them
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+  pub id: DayId,
+  pub name: String,
+  pub path: PathBuf,
+  pub part_1: Option<String>,
+  pub part_2: Option<String>,
+}
+
+impl Example {
+  // runs the example through its registered day, and describes every part that didn't give the
+  // expected answer
+  pub fn check(&self) -> Result<(), String> {
+    let entry = days::find(self.id).ok_or(format!("{}: {} isn't registered", self, self.id))?;
+    let mut data = vec![];
+    InputSource::File(self.path.display().to_string())
+      .load(&mut data)
+      .map_err(|e| format!("{}: {}", self, e))?;
+
+    let mut day = Day::new(self.id, entry.solver);
+    // a part without an answer might not even finish on this example
+    day.only = match (&self.part_1, &self.part_2) {
+      (Some(_), None) => Some(1),
+      (None, Some(_)) => Some(2),
+      _ => None,
+    };
+    day.run(&data);
+    day.part_1.check(self.part_1.as_deref());
+    day.part_2.check(self.part_2.as_deref());
+
+    let failures: Vec<String> = [
+      (1, &day.part_1, &self.part_1),
+      (2, &day.part_2, &self.part_2),
+    ]
+    .iter()
+    .filter(|(_, part, expected)| expected.is_some() && part.status != Status::Correct)
+    .map(|(n, part, expected)| describe(*n, part, expected.as_deref().unwrap_or_default()))
+    .collect();
+
+    if failures.is_empty() {
+      Ok(())
+    } else {
+      Err(format!("{}: {}", self, failures.join("; ")))
+    }
+  }
+}
+
+impl std::fmt::Display for Example {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{} example '{}'", self.id, self.name)
+  }
+}
+
+fn describe(n: u8, part: &Part, expected: &str) -> String {
+  match &part.result {
+    Ok(answer) => format!("part {} = {}, expected {}", n, answer, expected),
+    Err(e) => format!("part {} failed ({}), expected {}", n, e, expected),
+  }
+}
+
+// every example under data, in order of year, day and then name; examples without any answers
+// are left out, since there's nothing to check them against
+pub fn discover(data: &Path) -> Result<Vec<Example>, String> {
+  let mut examples = vec![];
+
+  for year_dir in read_dir(data)? {
+    let year = match numbered(&year_dir, "") {
+      Some(year) => year,
+      None => continue,
+    };
+    discover_year(year, &year_dir.join("examples"), &mut examples)?;
+  }
+
+  examples.sort_by(|a, b| (a.id, &a.name).cmp(&(b.id, &b.name)));
+  Ok(examples)
+}
+
+fn discover_year(year: u16, root: &Path, examples: &mut Vec<Example>) -> Result<(), String> {
+  for dir in read_dir(root)? {
+    let id = match numbered(&dir, "day-") {
+      Some(day) => DayId::new(year, day),
+      None => continue,
+    };
+
+    for path in read_dir(&dir)? {
+      if path.extension().and_then(|extension| extension.to_str()) != Some("txt") {
+        continue;
+      }
+      let name = path.file_stem().unwrap().to_string_lossy().to_string();
+      let (part_1, part_2) = answers(&path.with_extension("toml"))?;
+      if part_1.is_some() || part_2.is_some() {
+        examples.push(Example {
+          id,
+          name,
+          path,
+          part_1,
+          part_2,
+        });
+      }
+    }
+  }
+
+  Ok(())
+}
+
+// the number in the name of a directory like day-07 (with a prefix of "day-") or 2020
+fn numbered<T: std::str::FromStr>(dir: &Path, prefix: &str) -> Option<T> {
+  dir
+    .file_name()
+    .and_then(|name| name.to_str())
+    .and_then(|name| name.strip_prefix(prefix))
+    .and_then(|number| number.parse::<T>().ok())
+    .filter(|_| dir.is_dir())
+}
+
+// a missing directory just means there aren't any examples
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, String> {
+  match fs::read_dir(dir) {
+    Ok(entries) => entries
+      .map(|entry| entry.map(|entry| entry.path()))
+      .collect::<io::Result<Vec<PathBuf>>>()
+      .map_err(|e| format!("{}: {}", dir.display(), e)),
+    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+    Err(e) => Err(format!("{}: {}", dir.display(), e)),
+  }
+}
+
+fn answers(path: &Path) -> Result<(Option<String>, Option<String>), String> {
+  let text = match fs::read_to_string(path) {
+    Ok(text) => text,
+    Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok((None, None)),
+    Err(e) => return Err(format!("{}: {}", path.display(), e)),
+  };
+  let name = path.file_name().unwrap().to_string_lossy();
+  let parts = text
+    .parse::<toml::Value>()
+    .map_err(|e| e.to_string())
+    .and_then(|value| parse_parts(&name, &value))
+    .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+  let answer = |n| {
+    parts
+      .iter()
+      .find(|(part, _)| *part == n)
+      .map(|(_, answer)| answer.clone())
+  };
+  Ok((answer(1), answer(2)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::scratch_path;
+
+  // the examples in the repository, against the days they belong to
+  #[test]
+  fn test_examples() {
+    let examples = discover(&Path::new(env!("CARGO_MANIFEST_DIR")).join(DATA)).unwrap();
+    assert!(!examples.is_empty());
+
+    let failures: Vec<String> = examples.iter().filter_map(|e| e.check().err()).collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+  }
+
+  #[test]
+  fn test_discover() {
+    let root = scratch_path("examples");
+    let write = |path: &str, text: &str| {
+      let path = root.join(path);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, text).unwrap();
+    };
+    write("2020/examples/day-09/b.txt", "2\n");
+    write("2020/examples/day-09/b.toml", "part_2 = \"2\"\n");
+    write("2020/examples/day-09/a.txt", "1\n");
+    write("2020/examples/day-09/a.toml", "part_1 = 1\npart_2 = 3\n");
+    write("2020/examples/day-01/placeholder.txt", "");
+    write("2020/examples/day-01/placeholder.toml", "# part_1 = 0\n");
+    write("2020/examples/day-01/notes.md", "");
+    write("2020/examples/day-01/no-answers.txt", "1\n");
+    write("2020/examples/README.md", "");
+    write("2015/examples/day-25/example.txt", "1\n");
+    write("2015/examples/day-25/example.toml", "part_1 = 25\n");
+    write("README.md", "");
+
+    let examples = discover(&root).unwrap();
+    assert_eq!(
+      examples,
+      vec![
+        Example {
+          id: DayId::new(2015, 25),
+          name: "example".to_string(),
+          path: root.join("2015/examples/day-25/example.txt"),
+          part_1: Some("25".to_string()),
+          part_2: None,
+        },
+        Example {
+          id: DayId::new(2020, 9),
+          name: "a".to_string(),
+          path: root.join("2020/examples/day-09/a.txt"),
+          part_1: Some("1".to_string()),
+          part_2: Some("3".to_string()),
+        },
+        Example {
+          id: DayId::new(2020, 9),
+          name: "b".to_string(),
+          path: root.join("2020/examples/day-09/b.txt"),
+          part_1: None,
+          part_2: Some("2".to_string()),
+        },
+      ]
+    );
+
+    write("2020/examples/day-09/b.toml", "part_3 = 2\n");
+    assert_eq!(
+      discover(&root),
+      Err(format!(
+        "{}: [b.toml] has an unknown key: part_3",
+        root.join("2020/examples/day-09/b.toml").display()
+      ))
+    );
+    assert_eq!(discover(&root.join("nowhere")), Ok(vec![]));
+  }
+
+  #[test]
+  fn test_check() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(DATA);
+    let mut example = Example {
+      id: DayId::new(2020, 1),
+      name: "example".to_string(),
+      path: root.join("2020/examples/day-01/example.txt"),
+      part_1: Some("514579".to_string()),
+      part_2: None,
+    };
+    assert_eq!(example.check(), Ok(()));
+
+    example.part_1 = Some("1".to_string());
+    example.part_2 = Some("2".to_string());
+    assert_eq!(
+      example.check(),
+      Err(
+        "2020 day 1 example 'example': part 1 = 514579, expected 1; part 2 = 241861950, expected 2"
+          .to_string()
+      )
+    );
+
+    example.id = DayId::new(2015, 1);
+    assert_eq!(
+      example.check(),
+      Err("2015 day 1 example 'example': 2015 day 1 isn't registered".to_string())
+    );
+  }
+}
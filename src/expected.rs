@@ -0,0 +1,164 @@
+use crate::{data_dir, DayId};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+// expected answers, read from each year's data/<year>/answers.toml:
+//
+//   [day-07]
+//   part_1 = 287
+//   part_2 = 48160
+//
+// answers can be integers or strings; anything that doesn't fit in an i64 has to be a string
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+  answers: HashMap<(DayId, u8), String>,
+}
+
+impl ExpectedAnswers {
+  // the answers for every one of years
+  pub fn load_years(years: &[u16]) -> Result<ExpectedAnswers, String> {
+    let mut expected = ExpectedAnswers::default();
+    for year in years {
+      let filename = format!("{}/answers.toml", data_dir(*year));
+      expected
+        .answers
+        .extend(ExpectedAnswers::load(*year, &filename)?.answers);
+    }
+
+    Ok(expected)
+  }
+
+  // a missing file isn't an error, it just means every answer is unknown
+  pub fn load(year: u16, filename: &str) -> Result<ExpectedAnswers, String> {
+    match fs::read_to_string(filename) {
+      Ok(text) => {
+        ExpectedAnswers::from_toml(year, &text).map_err(|e| format!("{}: {}", filename, e))
+      }
+      Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+      Err(e) => Err(format!("{}: {}", filename, e)),
+    }
+  }
+
+  pub fn from_toml(year: u16, text: &str) -> Result<ExpectedAnswers, String> {
+    let table = match text.parse::<toml::Value>() {
+      Ok(toml::Value::Table(table)) => table,
+      Ok(_) => return Err("expected a table".to_string()),
+      Err(e) => return Err(e.to_string()),
+    };
+    let mut answers = HashMap::new();
+
+    for (key, parts) in table {
+      let day = key
+        .strip_prefix("day-")
+        .and_then(|day| day.parse::<u8>().ok())
+        .ok_or(format!("expected [day-NN], found [{}]", key))?;
+      for (part, answer) in parse_parts(&key, &parts)? {
+        answers.insert((DayId::new(year, day), part), answer);
+      }
+    }
+
+    Ok(ExpectedAnswers { answers })
+  }
+
+  pub fn get(&self, id: DayId, part: u8) -> Option<&str> {
+    self.answers.get(&(id, part)).map(String::as_str)
+  }
+}
+
+// the answers in a table of part_1 and part_2, either of which can be missing; key is the
+// table's name, for error messages
+pub(crate) fn parse_parts(key: &str, parts: &toml::Value) -> Result<Vec<(u8, String)>, String> {
+  let parts = parts
+    .as_table()
+    .ok_or(format!("[{}] should be a table", key))?;
+  let mut answers = vec![];
+
+  for (name, value) in parts {
+    let part = match name.as_str() {
+      "part_1" => 1,
+      "part_2" => 2,
+      _ => return Err(format!("[{}] has an unknown key: {}", key, name)),
+    };
+    let answer = match value {
+      toml::Value::Integer(answer) => answer.to_string(),
+      toml::Value::String(answer) => answer.clone(),
+      _ => return Err(format!("{}.{} should be an integer or a string", key, name)),
+    };
+    answers.push((part, answer));
+  }
+
+  Ok(answers)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+  Correct,
+  // holds the expected answer
+  Wrong(String),
+  Unknown,
+}
+
+impl fmt::Display for Status {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Status::Correct => write!(f, "correct"),
+      Status::Wrong(expected) => write!(f, "wrong, expected {}", expected),
+      Status::Unknown => write!(f, "unknown"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_toml() {
+    let expected = ExpectedAnswers::from_toml(
+      2020,
+      "[day-08]\npart_1 = -3\npart_2 = 1532\n\n[day-13]\npart_2 = \"225850756401039\"\n",
+    )
+    .unwrap();
+    assert_eq!(expected.get(DayId::new(2020, 8), 1), Some("-3"));
+    assert_eq!(expected.get(DayId::new(2020, 8), 2), Some("1532"));
+    assert_eq!(expected.get(DayId::new(2020, 13), 1), None);
+    assert_eq!(
+      expected.get(DayId::new(2020, 13), 2),
+      Some("225850756401039")
+    );
+    assert_eq!(expected.get(DayId::new(2020, 14), 1), None);
+  }
+
+  #[test]
+  fn test_from_toml_errors() {
+    assert!(ExpectedAnswers::from_toml(2020, "[day-8\npart_1 = 1").is_err());
+    assert_eq!(
+      ExpectedAnswers::from_toml(2020, "[day8]\npart_1 = 1").unwrap_err(),
+      "expected [day-NN], found [day8]"
+    );
+    assert_eq!(
+      ExpectedAnswers::from_toml(2020, "[day-08]\npart_3 = 1").unwrap_err(),
+      "[day-08] has an unknown key: part_3"
+    );
+    assert_eq!(
+      ExpectedAnswers::from_toml(2020, "[day-08]\npart_1 = 1.5").unwrap_err(),
+      "day-08.part_1 should be an integer or a string"
+    );
+  }
+
+  #[test]
+  fn test_load_missing_file() {
+    let expected = ExpectedAnswers::load(2020, "data/no-such-answers.toml").unwrap();
+    assert_eq!(expected.get(DayId::new(2020, 1), 1), None);
+  }
+
+  #[test]
+  fn test_load_years() {
+    // the tests run at the top of the repository
+    let expected = ExpectedAnswers::load_years(&[2015, 2020]).unwrap();
+    assert_eq!(expected.get(DayId::new(2020, 2), 1), Some("655"));
+    assert_eq!(expected.get(DayId::new(2015, 2), 1), None);
+  }
+}
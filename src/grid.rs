@@ -0,0 +1,282 @@
+use crate::{AocError, Input};
+use std::convert::TryFrom;
+use std::ops::{Index, IndexMut};
+
+// (x, y), with y increasing downwards like the lines of the input; signed so that stepping off
+// an edge gives a point outside the grid rather than an overflow
+pub type Point = (isize, isize);
+pub type Direction = (isize, isize);
+
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const ADJACENT: [Direction; 8] = [
+  (0, -1),
+  (1, -1),
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+  (-1, -1),
+];
+
+// a rectangle of cells, stored row by row
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+  pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+    Grid {
+      width,
+      height,
+      cells: vec![value; width * height],
+    }
+  }
+}
+
+impl<T: TryFrom<char>> Grid<T> {
+  // one character per cell, e.g. Grid::<char>::parse(data)
+  pub fn parse(data: &[&str]) -> Result<Grid<T>, AocError> {
+    Input::new(data).grid()
+  }
+}
+
+impl<T> Grid<T> {
+  // every row has to be the same length
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+    let width = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != width) {
+      return None;
+    }
+
+    Some(Grid {
+      width,
+      height: rows.len(),
+      cells: rows.into_iter().flatten().collect(),
+    })
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn contains(&self, (x, y): Point) -> bool {
+    x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+  }
+
+  pub fn get(&self, point: Point) -> Option<&T> {
+    self.index_of(point).map(|ix| &self.cells[ix])
+  }
+
+  pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+    self.index_of(point).map(move |ix| &mut self.cells[ix])
+  }
+
+  // treats the grid as if it repeats forever in every direction; None only if the grid is empty
+  pub fn get_wrapping(&self, (x, y): Point) -> Option<&T> {
+    if self.width == 0 || self.height == 0 {
+      return None;
+    }
+
+    let x = x.rem_euclid(self.width as isize);
+    let y = y.rem_euclid(self.height as isize);
+    self.get((x, y))
+  }
+
+  // every cell with its point, row by row
+  pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+    let width = self.width;
+    self
+      .cells
+      .iter()
+      .enumerate()
+      .map(move |(ix, cell)| (((ix % width) as isize, (ix / width) as isize), cell))
+  }
+
+  // the cells next to point in each of directions, skipping any that fall outside the grid
+  pub fn neighbors<'a>(
+    &'a self,
+    (x, y): Point,
+    directions: &'a [Direction],
+  ) -> impl Iterator<Item = (Point, &'a T)> {
+    directions.iter().filter_map(move |(dx, dy)| {
+      let point = (x + dx, y + dy);
+      self.get(point).map(|cell| (point, cell))
+    })
+  }
+
+  pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+    self.neighbors(point, &ORTHOGONAL)
+  }
+
+  pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+    self.neighbors(point, &ADJACENT)
+  }
+
+  // the cells seen looking from point (not included) in direction, nearest first, up to the edge;
+  // looking in (0, 0) sees nothing, rather than the same point forever
+  pub fn cast(&self, (x, y): Point, (dx, dy): Direction) -> impl Iterator<Item = (Point, &T)> {
+    (1..)
+      .take_while(move |_| (dx, dy) != (0, 0))
+      .map(move |n| (x + dx * n, y + dy * n))
+      .map(move |point| self.get(point).map(|cell| (point, cell)))
+      .take_while(Option::is_some)
+      .flatten()
+  }
+
+  fn index_of(&self, point: Point) -> Option<usize> {
+    if self.contains(point) {
+      Some(point.1 as usize * self.width + point.0 as usize)
+    } else {
+      None
+    }
+  }
+}
+
+impl<T> Index<Point> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, point: Point) -> &T {
+    match self.index_of(point) {
+      Some(ix) => &self.cells[ix],
+      None => panic!(
+        "{:?} is outside a {}x{} grid",
+        point, self.width, self.height
+      ),
+    }
+  }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+  fn index_mut(&mut self, point: Point) -> &mut T {
+    match self.index_of(point) {
+      Some(ix) => &mut self.cells[ix],
+      None => panic!(
+        "{:?} is outside a {}x{} grid",
+        point, self.width, self.height
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample() -> Grid<char> {
+    Grid::parse(&["abc", "def", "ghi"]).unwrap()
+  }
+
+  #[test]
+  fn test_parse() {
+    let grid = sample();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 3);
+    assert_eq!(grid[(2, 0)], 'c');
+    assert_eq!(grid[(0, 2)], 'g');
+
+    assert_eq!(
+      Grid::<char>::parse(&["abc", "de"]),
+      Err(AocError::parse(1, "de", "expected 3 columns, found 2"))
+    );
+  }
+
+  #[test]
+  fn test_from_rows() {
+    assert_eq!(
+      Grid::from_rows(vec![vec![1, 2], vec![3, 4]]),
+      Some(Grid {
+        width: 2,
+        height: 2,
+        cells: vec![1, 2, 3, 4]
+      })
+    );
+    assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    assert_eq!(
+      Grid::new(2, 1, 0),
+      Grid::from_rows(vec![vec![0, 0]]).unwrap()
+    );
+  }
+
+  #[test]
+  fn test_get() {
+    let mut grid = sample();
+    assert_eq!(grid.get((1, 1)), Some(&'e'));
+    assert_eq!(grid.get((-1, 0)), None);
+    assert_eq!(grid.get((0, -1)), None);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+
+    *grid.get_mut((1, 1)).unwrap() = 'E';
+    grid[(2, 2)] = 'I';
+    assert_eq!(grid[(1, 1)], 'E');
+    assert_eq!(grid.get((2, 2)), Some(&'I'));
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_index_out_of_bounds() {
+    let _ = sample()[(3, 3)];
+  }
+
+  #[test]
+  fn test_get_wrapping() {
+    let grid = sample();
+    assert_eq!(grid.get_wrapping((4, 0)), Some(&'b'));
+    assert_eq!(grid.get_wrapping((-1, 0)), Some(&'c'));
+    assert_eq!(grid.get_wrapping((9, 10)), Some(&'d'));
+
+    let empty: Grid<char> = Grid::from_rows(vec![]).unwrap();
+    assert_eq!(empty.get_wrapping((0, 0)), None);
+    let empty: Grid<char> = Grid::from_rows(vec![vec![], vec![]]).unwrap();
+    assert_eq!(empty.get_wrapping((1, 1)), None);
+  }
+
+  #[test]
+  fn test_iter() {
+    let grid = sample();
+    let cells: Vec<(Point, &char)> = grid.iter().take(4).collect();
+    assert_eq!(
+      cells,
+      vec![
+        ((0, 0), &'a'),
+        ((1, 0), &'b'),
+        ((2, 0), &'c'),
+        ((0, 1), &'d')
+      ]
+    );
+  }
+
+  #[test]
+  fn test_neighbors() {
+    fn chars<'a>(cells: impl Iterator<Item = (Point, &'a char)>) -> String {
+      cells.map(|(_, c)| *c).collect()
+    }
+
+    let grid = sample();
+    assert_eq!(chars(grid.neighbors4((1, 1))), "bfhd");
+    assert_eq!(chars(grid.neighbors8((1, 1))), "bcfihgda");
+    assert_eq!(chars(grid.neighbors4((0, 0))), "bd");
+    assert_eq!(chars(grid.neighbors8((2, 2))), "fhe");
+    assert_eq!(chars(grid.neighbors((0, 0), &[(2, 2), (3, 3)])), "i");
+  }
+
+  #[test]
+  fn test_cast() {
+    let grid = sample();
+    let seen: Vec<(Point, &char)> = grid.cast((0, 0), (1, 1)).collect();
+    assert_eq!(seen, vec![((1, 1), &'e'), ((2, 2), &'i')]);
+
+    let seen: String = grid.cast((2, 1), (-1, 0)).map(|(_, c)| *c).collect();
+    assert_eq!(seen, "ed");
+    assert_eq!(grid.cast((0, 0), (0, -1)).count(), 0);
+    assert_eq!(grid.cast((1, 1), (0, 0)).count(), 0);
+  }
+}
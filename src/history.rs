@@ -0,0 +1,362 @@
+use crate::{format_duration, Day, DayId, Part};
+use serde_json::json;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// every run of the aoc runner, a day per line
+pub const HISTORY: &str = "data/history.jsonl";
+
+// every run before runs had a year was of 2020
+const YEAR_BEFORE_YEARS: u16 = 2020;
+
+// differences smaller than this are put down to noise, however large they are relatively
+const NOISE: Duration = Duration::from_millis(1);
+
+// one day of one run
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+  // when the run started, in milliseconds since the epoch; every day in a run has the same one
+  pub run: u64,
+  // from git describe, so it ends in -dirty if there were uncommitted changes
+  pub commit: Option<String>,
+  pub id: DayId,
+  // None if the part failed
+  pub answers: [Option<String>; 2],
+  pub durations: [Duration; 2],
+  pub duration: Duration,
+}
+
+impl Record {
+  pub fn new(run: u64, commit: Option<&str>, day: &Day) -> Record {
+    let answer = |part: &Part| part.result.as_ref().ok().map(|answer| answer.to_string());
+    Record {
+      run,
+      commit: commit.map(String::from),
+      id: day.id,
+      answers: [answer(&day.part_1), answer(&day.part_2)],
+      durations: [day.part_1.duration(), day.part_2.duration()],
+      duration: day.duration(),
+    }
+  }
+
+  pub fn to_json(&self) -> serde_json::Value {
+    json!({
+      "run": self.run,
+      "commit": self.commit,
+      "year": self.id.year,
+      "day": self.id.day,
+      "part_1": {
+        "answer": self.answers[0],
+        "duration_ns": self.durations[0].as_nanos() as u64,
+      },
+      "part_2": {
+        "answer": self.answers[1],
+        "duration_ns": self.durations[1].as_nanos() as u64,
+      },
+      "duration_ns": self.duration.as_nanos() as u64,
+    })
+  }
+
+  pub fn from_json(value: &serde_json::Value) -> Option<Record> {
+    let nanos = |value: &serde_json::Value| value.as_u64().map(Duration::from_nanos);
+    let answer = |part: &str| value[part]["answer"].as_str().map(String::from);
+
+    Some(Record {
+      run: value["run"].as_u64()?,
+      commit: value["commit"].as_str().map(String::from),
+      id: DayId::new(
+        value["year"]
+          .as_u64()
+          .map_or(YEAR_BEFORE_YEARS, |year| year as u16),
+        value["day"].as_u64()? as u8,
+      ),
+      answers: [answer("part_1"), answer("part_2")],
+      durations: [
+        nanos(&value["part_1"]["duration_ns"])?,
+        nanos(&value["part_2"]["duration_ns"])?,
+      ],
+      duration: nanos(&value["duration_ns"])?,
+    })
+  }
+}
+
+// a new run's id
+pub fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |since| since.as_millis() as u64)
+}
+
+// the commit the working tree is at, or None if git isn't around to say
+pub fn commit() -> Option<String> {
+  let output = Command::new("git")
+    .args(["describe", "--always", "--dirty"])
+    .output()
+    .ok()
+    .filter(|output| output.status.success())?;
+
+  Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn append(path: &str, records: &[Record]) -> io::Result<()> {
+  let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+  let mut lines = String::new();
+  for record in records {
+    lines += &format!("{}\n", record.to_json());
+  }
+
+  file.write_all(lines.as_bytes())
+}
+
+// oldest first; a missing file is an empty history
+pub fn load(path: &str) -> Result<Vec<Record>, String> {
+  let text = match fs::read_to_string(path) {
+    Ok(text) => text,
+    Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+    Err(e) => return Err(format!("{}: {}", path, e)),
+  };
+
+  text
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(ix, line)| {
+      serde_json::from_str(line)
+        .ok()
+        .as_ref()
+        .and_then(Record::from_json)
+        .ok_or(format!("{}, line {}: not a run of a day", path, ix + 1))
+    })
+    .collect()
+}
+
+// the days of the most recent run
+pub fn latest(records: &[Record]) -> Vec<&Record> {
+  let run = records.iter().map(|record| record.run).max();
+  records
+    .iter()
+    .filter(|record| Some(record.run) == run)
+    .collect()
+}
+
+// the last time record's day was run before record
+pub fn previous<'a>(records: &'a [Record], record: &Record) -> Option<&'a Record> {
+  records
+    .iter()
+    .rev()
+    .find(|earlier| earlier.id == record.id && earlier.run < record.run)
+}
+
+// the last time record's day was run before record, at a commit starting with commit
+pub fn at_commit<'a>(records: &'a [Record], record: &Record, commit: &str) -> Option<&'a Record> {
+  records
+    .iter()
+    .rev()
+    .filter(|earlier| earlier.id == record.id && earlier.run < record.run)
+    .find(|earlier| {
+      earlier
+        .commit
+        .as_ref()
+        .is_some_and(|c| c.starts_with(commit))
+    })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+  Answer {
+    part: u8,
+    from: Option<String>,
+    to: Option<String>,
+  },
+  // part is None for the whole day
+  Slower {
+    part: Option<u8>,
+    from: Duration,
+    to: Duration,
+  },
+}
+
+// what's different about new compared to old: any answer that changed, and anything that got
+// slower by more than threshold (e.g. 0.1 for 10%)
+pub fn compare(old: &Record, new: &Record, threshold: f64) -> Vec<Change> {
+  let mut changes = vec![];
+
+  for part in 0..2 {
+    if old.answers[part] != new.answers[part] {
+      changes.push(Change::Answer {
+        part: part as u8 + 1,
+        from: old.answers[part].clone(),
+        to: new.answers[part].clone(),
+      });
+    }
+  }
+
+  let durations = [
+    (Some(1), old.durations[0], new.durations[0]),
+    (Some(2), old.durations[1], new.durations[1]),
+    (None, old.duration, new.duration),
+  ];
+  for (part, from, to) in durations.iter().cloned() {
+    if to > from + NOISE && to.as_secs_f64() > from.as_secs_f64() * (1.0 + threshold) {
+      changes.push(Change::Slower { part, from, to });
+    }
+  }
+
+  changes
+}
+
+impl fmt::Display for Change {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let answer = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "failed".to_string());
+
+    match self {
+      Change::Answer { part, from, to } => write!(
+        f,
+        "part {} changed from {} to {}",
+        part,
+        answer(from),
+        answer(to)
+      ),
+      Change::Slower { part, from, to } => {
+        let percent = (to.as_secs_f64() / from.as_secs_f64() - 1.0) * 100.0;
+        match part {
+          Some(part) => write!(f, "part {}", part)?,
+          None => write!(f, "total")?,
+        }
+        write!(
+          f,
+          " slowed down from {} to {} (+{:.0}%)",
+          format_duration(*from),
+          format_duration(*to),
+          percent
+        )
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{scratch_path, Answer, AocError, Solver};
+
+  fn record(run: u64, commit: &str, day: u8, answer: Option<&str>, millis: u64) -> Record {
+    Record {
+      run,
+      commit: Some(commit.to_string()),
+      id: DayId::new(2020, day),
+      answers: [Some("1".to_string()), answer.map(String::from)],
+      durations: [Duration::from_millis(millis), Duration::from_millis(millis)],
+      duration: Duration::from_millis(millis * 2),
+    }
+  }
+
+  #[test]
+  fn test_record() {
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Integer(42))
+    }
+
+    fn no_solution(_data: &[&str]) -> Result<Answer, AocError> {
+      Err(AocError::no_solution("nope"))
+    }
+
+    let mut day = Day::new(DayId::new(2020, 7), Solver::Parts(forty_two, no_solution));
+    day.run(&[]);
+    let record = Record::new(1000, Some("abc1234"), &day);
+    assert_eq!(record.answers, [Some("42".to_string()), None]);
+    assert_eq!(record.duration, day.duration());
+
+    let json = record.to_json();
+    assert_eq!(json["part_1"]["answer"], "42");
+    assert_eq!(Record::from_json(&json), Some(record.clone()));
+
+    // from before runs had a year
+    let mut json = json;
+    json.as_object_mut().unwrap().remove("year");
+    assert_eq!(Record::from_json(&json), Some(record));
+  }
+
+  #[test]
+  fn test_append_and_load() {
+    let path = scratch_path("history.jsonl");
+    let path = path.to_str().unwrap();
+    assert_eq!(load(path), Ok(vec![]));
+
+    let first = vec![
+      record(1, "abc", 1, Some("2"), 5),
+      record(1, "abc", 2, None, 5),
+    ];
+    let second = vec![record(2, "def", 1, Some("3"), 5)];
+    append(path, &first).unwrap();
+    append(path, &second).unwrap();
+    let records = load(path).unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[2], second[0]);
+
+    assert_eq!(latest(&records), vec![&second[0]]);
+    assert_eq!(previous(&records, &second[0]), Some(&first[0]));
+    assert_eq!(previous(&records, &first[0]), None);
+    assert_eq!(at_commit(&records, &second[0], "ab"), Some(&first[0]));
+    assert_eq!(at_commit(&records, &second[0], "def"), None);
+
+    fs::write(path, "{\"run\": 1}\n").unwrap();
+    assert_eq!(
+      load(path),
+      Err(format!("{}, line 1: not a run of a day", path))
+    );
+  }
+
+  #[test]
+  fn test_compare() {
+    let old = record(1, "abc", 1, Some("2"), 10);
+    assert_eq!(compare(&old, &old, 0.1), vec![]);
+    // within the threshold
+    assert_eq!(
+      compare(&old, &record(2, "abc", 1, Some("2"), 11), 0.1),
+      vec![]
+    );
+
+    let changes = compare(&old, &record(2, "abc", 1, None, 20), 0.1);
+    assert_eq!(
+      changes,
+      vec![
+        Change::Answer {
+          part: 2,
+          from: Some("2".to_string()),
+          to: None
+        },
+        Change::Slower {
+          part: Some(1),
+          from: Duration::from_millis(10),
+          to: Duration::from_millis(20)
+        },
+        Change::Slower {
+          part: Some(2),
+          from: Duration::from_millis(10),
+          to: Duration::from_millis(20)
+        },
+        Change::Slower {
+          part: None,
+          from: Duration::from_millis(20),
+          to: Duration::from_millis(40)
+        },
+      ]
+    );
+    assert_eq!(changes[0].to_string(), "part 2 changed from 2 to failed");
+    assert_eq!(
+      changes[3].to_string(),
+      "total slowed down from 20.000 ms to 40.000 ms (+100%)"
+    );
+
+    // far slower, but only by a few microseconds
+    let mut fast = record(1, "abc", 1, Some("2"), 0);
+    fast.durations[0] = Duration::from_micros(10);
+    let mut slower = fast.clone();
+    slower.durations[0] = Duration::from_micros(30);
+    assert_eq!(compare(&fast, &slower, 0.1), vec![]);
+  }
+}
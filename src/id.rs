@@ -0,0 +1,115 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// which puzzle a day is: adventofcode.com has a calendar of days 1 to 25 every year
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+  pub year: u16,
+  pub day: u8,
+}
+
+// 2020's inputs were kept straight in data/ before there were other years
+const LEGACY_YEAR: u16 = 2020;
+
+// everything for a year lives under data/<year>/: inputs, answers.toml and examples/
+pub fn data_dir(year: u16) -> String {
+  format!("data/{}", year)
+}
+
+impl DayId {
+  pub const fn new(year: u16, day: u8) -> DayId {
+    DayId { year, day }
+  }
+
+  pub fn input_path(&self) -> String {
+    format!("{}/day-{:02}-input.txt", data_dir(self.year), self.day)
+  }
+
+  // where the input used to be, if this day had an input before the data was split by year
+  pub fn legacy_input_path(&self) -> Option<String> {
+    if self.year == LEGACY_YEAR {
+      Some(format!("data/day-{:02}-input.txt", self.day))
+    } else {
+      None
+    }
+  }
+
+  // the input_path() if there's anything in it, otherwise the legacy_input_path() if there's
+  // anything in that; aoc new leaves an empty input behind as a placeholder
+  pub fn find_input(&self) -> Option<String> {
+    let has_input = |path: &String| {
+      Path::new(path)
+        .metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.len() > 0)
+    };
+
+    Some(self.input_path())
+      .filter(has_input)
+      .or_else(|| self.legacy_input_path().filter(has_input))
+  }
+
+  // anything a day can be told without rebuilding it, like day 4's passport rules; the runner
+  // passes it to the day along with the input, see Input::settings()
+  pub fn settings_path(&self) -> String {
+    format!("{}/day-{:02}-settings.toml", data_dir(self.year), self.day)
+  }
+
+  // what's in settings_path(), or None if there's no such file
+  pub fn load_settings(&self) -> io::Result<Option<String>> {
+    match fs::read_to_string(self.settings_path()) {
+      Ok(text) => Ok(Some(text)),
+      Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+      Err(e) => Err(e),
+    }
+  }
+}
+
+impl fmt::Display for DayId {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} day {}", self.year, self.day)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_paths() {
+    let id = DayId::new(2020, 7);
+    assert_eq!(id.input_path(), "data/2020/day-07-input.txt");
+    assert_eq!(
+      id.legacy_input_path(),
+      Some("data/day-07-input.txt".to_string())
+    );
+    assert_eq!(
+      DayId::new(2015, 7).input_path(),
+      "data/2015/day-07-input.txt"
+    );
+    assert_eq!(DayId::new(2015, 7).legacy_input_path(), None);
+    assert_eq!(DayId::new(2015, 25).find_input(), None);
+    assert_eq!(id.settings_path(), "data/2020/day-07-settings.toml");
+    assert_eq!(DayId::new(2015, 25).load_settings().unwrap(), None);
+  }
+
+  #[test]
+  fn test_order() {
+    let mut ids = vec![
+      DayId::new(2020, 1),
+      DayId::new(2015, 25),
+      DayId::new(2020, 0),
+    ];
+    ids.sort();
+    assert_eq!(
+      ids,
+      vec![
+        DayId::new(2015, 25),
+        DayId::new(2020, 0),
+        DayId::new(2020, 1)
+      ]
+    );
+    assert_eq!(DayId::new(2020, 7).to_string(), "2020 day 7");
+  }
+}
@@ -0,0 +1,300 @@
+use crate::grid::Grid;
+use crate::AocError;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+// a view of a day's input (or part of it) that knows the usual ways puzzles lay it out;
+// nothing is copied, everything borrows from the lines passed to the part
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Input<'a> {
+  lines: &'a [&'a str],
+  // index of the first line within the whole input, so errors point at the right line
+  offset: usize,
+  // see settings()
+  settings: Option<&'a str>,
+}
+
+// a paragraph that may start with a header line ending in ':', like "your ticket:"
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Section<'a> {
+  // the header without the ':'
+  pub header: Option<&'a str>,
+  pub body: Input<'a>,
+  offset: usize,
+}
+
+impl<'a> Section<'a> {
+  // index of the section's first line (the header, if there is one) within the whole input
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+}
+
+impl<'a> Input<'a> {
+  pub fn new(lines: &'a [&'a str]) -> Input<'a> {
+    Input {
+      lines,
+      offset: 0,
+      settings: None,
+    }
+  }
+
+  pub fn with_settings(self, settings: Option<&'a str>) -> Input<'a> {
+    Input { settings, ..self }
+  }
+
+  // the text of the day's settings file, if it has one (see DayId::settings_path()); every view
+  // of the input shares it
+  pub fn settings(&self) -> Option<&'a str> {
+    self.settings
+  }
+
+  pub fn lines(&self) -> &'a [&'a str] {
+    self.lines
+  }
+
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+
+  pub fn len(&self) -> usize {
+    self.lines.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.lines.is_empty()
+  }
+
+  // ix is the index of the line within this view, not the whole input
+  pub fn error(&self, ix: usize, message: impl Into<String>) -> AocError {
+    AocError::parse(
+      self.offset + ix,
+      self.lines.get(ix).cloned().unwrap_or(""),
+      message,
+    )
+  }
+
+  // groups of lines separated by one or more blank lines
+  pub fn paragraphs(&self) -> impl Iterator<Item = Input<'a>> {
+    let lines = self.lines;
+    let offset = self.offset;
+    let settings = self.settings;
+    let mut start = 0;
+
+    std::iter::from_fn(move || {
+      while start < lines.len() && is_blank(lines[start]) {
+        start += 1;
+      }
+      if start == lines.len() {
+        return None;
+      }
+
+      let end = lines[start..]
+        .iter()
+        .position(|line| is_blank(line))
+        .map_or(lines.len(), |len| start + len);
+      let paragraph = Input {
+        lines: &lines[start..end],
+        offset: offset + start,
+        settings,
+      };
+      start = end;
+
+      Some(paragraph)
+    })
+  }
+
+  pub fn sections(&self) -> impl Iterator<Item = Section<'a>> {
+    self.paragraphs().map(
+      |paragraph| match paragraph.lines[0].trim_end().strip_suffix(':') {
+        Some(header) => Section {
+          header: Some(header),
+          body: Input {
+            lines: &paragraph.lines[1..],
+            offset: paragraph.offset + 1,
+            settings: paragraph.settings,
+          },
+          offset: paragraph.offset,
+        },
+        None => Section {
+          header: None,
+          body: paragraph,
+          offset: paragraph.offset,
+        },
+      },
+    )
+  }
+
+  // every number in the input, whether they're one to a line or separated by commas or spaces
+  pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, AocError>
+  where
+    T::Err: ToString,
+  {
+    let mut numbers = vec![];
+
+    for (ix, line) in self.lines.iter().enumerate() {
+      let separators = |c: char| c == ',' || c.is_whitespace();
+      for token in line.split(separators).filter(|token| !token.is_empty()) {
+        let column = token.as_ptr() as usize - line.as_ptr() as usize;
+        numbers.push(
+          token
+            .parse::<T>()
+            .map_err(|e| self.error(ix, e.to_string()).at_column(column))?,
+        );
+      }
+    }
+
+    Ok(numbers)
+  }
+
+  // one character per cell; every row has to be as wide as the first
+  pub fn grid<T: TryFrom<char>>(&self) -> Result<Grid<T>, AocError> {
+    let width = self.lines.first().map_or(0, |line| line.chars().count());
+    let mut rows = vec![];
+
+    for (ix, line) in self.lines.iter().enumerate() {
+      let mut row = Vec::with_capacity(width);
+      for (column, c) in line.char_indices() {
+        row.push(T::try_from(c).map_err(|_| {
+          self
+            .error(ix, format!("don't know what to do with '{}'", c))
+            .at_column(column)
+        })?);
+      }
+
+      if row.len() != width {
+        return Err(self.error(
+          ix,
+          format!("expected {} columns, found {}", width, row.len()),
+        ));
+      }
+      rows.push(row);
+    }
+
+    Ok(Grid::from_rows(rows).unwrap())
+  }
+}
+
+impl<'a> From<&'a [&'a str]> for Input<'a> {
+  fn from(lines: &'a [&'a str]) -> Input<'a> {
+    Input::new(lines)
+  }
+}
+
+fn is_blank(line: &str) -> bool {
+  line.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, PartialEq)]
+  enum Square {
+    Open,
+    Tree,
+  }
+
+  impl TryFrom<char> for Square {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Square, ()> {
+      match c {
+        '.' => Ok(Square::Open),
+        '#' => Ok(Square::Tree),
+        _ => Err(()),
+      }
+    }
+  }
+
+  #[test]
+  fn test_paragraphs() {
+    let data = ["", "abc", "", "", "a", "b", "  ", "ab", "ac", ""];
+    let paragraphs: Vec<Input> = Input::new(&data).paragraphs().collect();
+    assert_eq!(paragraphs.len(), 3);
+    assert_eq!(paragraphs[0].lines(), &["abc"]);
+    assert_eq!(paragraphs[1].lines(), &["a", "b"]);
+    assert_eq!(paragraphs[1].offset(), 4);
+    assert_eq!(paragraphs[2].lines(), &["ab", "ac"]);
+    assert_eq!(paragraphs[2].offset(), 7);
+
+    assert_eq!(Input::new(&[]).paragraphs().count(), 0);
+    assert_eq!(Input::new(&["", ""]).paragraphs().count(), 0);
+  }
+
+  #[test]
+  fn test_sections() {
+    let data = [
+      "class: 1-3 or 5-7",
+      "",
+      "your ticket:",
+      "7,1,14",
+      "",
+      "nearby tickets:",
+      "7,3,47",
+      "40,4,50",
+    ];
+    let sections: Vec<Section> = Input::new(&data).sections().collect();
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].header, None);
+    assert_eq!(sections[0].body.lines(), &["class: 1-3 or 5-7"]);
+    assert_eq!(sections[1].header, Some("your ticket"));
+    assert_eq!(sections[1].offset(), 2);
+    assert_eq!(sections[1].body.lines(), &["7,1,14"]);
+    assert_eq!(sections[1].body.offset(), 3);
+    assert_eq!(sections[2].header, Some("nearby tickets"));
+    assert_eq!(sections[2].body.len(), 2);
+  }
+
+  #[test]
+  fn test_numbers() {
+    assert_eq!(
+      Input::new(&["1721", "979", "366"]).numbers::<u32>(),
+      Ok(vec![1721, 979, 366])
+    );
+    assert_eq!(Input::new(&["0,3,6"]).numbers::<u64>(), Ok(vec![0, 3, 6]));
+    assert_eq!(
+      Input::new(&["-1, 2", "3 -4"]).numbers::<i32>(),
+      Ok(vec![-1, 2, 3, -4])
+    );
+    assert_eq!(
+      Input::new(&["0", "3,six"]).numbers::<u32>(),
+      Err(AocError::parse(1, "3,six", "invalid digit found in string").at_column(2))
+    );
+  }
+
+  #[test]
+  fn test_grid() {
+    let data = ["..#", "#.."];
+    assert_eq!(
+      Input::new(&data).grid::<Square>(),
+      Ok(
+        Grid::from_rows(vec![
+          vec![Square::Open, Square::Open, Square::Tree],
+          vec![Square::Tree, Square::Open, Square::Open]
+        ])
+        .unwrap()
+      )
+    );
+    assert_eq!(
+      Input::new(&data).grid::<char>().map(|grid| grid[(2, 0)]),
+      Ok('#')
+    );
+
+    assert_eq!(
+      Input::new(&["..#", ".L."]).grid::<Square>(),
+      Err(AocError::parse(1, ".L.", "don't know what to do with 'L'").at_column(1))
+    );
+    assert_eq!(
+      Input::new(&["..#", "."]).grid::<Square>(),
+      Err(AocError::parse(1, ".", "expected 3 columns, found 1"))
+    );
+  }
+
+  #[test]
+  fn test_error() {
+    let data = ["a", "", "b", "c"];
+    let paragraph = Input::new(&data).paragraphs().nth(1).unwrap();
+    assert_eq!(paragraph.error(1, "nope"), AocError::parse(3, "c", "nope"));
+  }
+}
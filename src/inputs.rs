@@ -0,0 +1,227 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+// puzzle inputs downloaded so far, kept as <root>/<year>/day-NN-input.txt
+pub struct InputCache {
+  root: PathBuf,
+}
+
+impl InputCache {
+  pub fn new<P: Into<PathBuf>>(root: P) -> InputCache {
+    InputCache { root: root.into() }
+  }
+
+  pub fn path(&self, year: u16, day: u8) -> PathBuf {
+    self
+      .root
+      .join(year.to_string())
+      .join(format!("day-{:02}-input.txt", day))
+  }
+
+  // an empty file is a placeholder left by aoc new, so it doesn't count
+  pub fn contains(&self, year: u16, day: u8) -> bool {
+    fs::metadata(self.path(year, day)).is_ok_and(|meta| meta.is_file() && meta.len() > 0)
+  }
+
+  pub fn store(&self, year: u16, day: u8, input: &str) -> io::Result<PathBuf> {
+    let path = self.path(year, day);
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+
+    Ok(path)
+  }
+
+  // only goes to the server if the input isn't cached yet
+  pub fn fetch_missing(&self, year: u16, day: u8, fetcher: &Fetcher) -> Result<PathBuf, String> {
+    if self.contains(year, day) {
+      return Ok(self.path(year, day));
+    }
+
+    let input = fetcher.fetch(year, day)?;
+    self
+      .store(year, day, &input)
+      .map_err(|e| format!("cannot write {}: {}", self.path(year, day).display(), e))
+  }
+}
+
+// downloads puzzle inputs, which are different for every user, so it needs the value of the
+// session cookie set when logging in to adventofcode.com
+pub struct Fetcher {
+  base_url: String,
+  session: String,
+  agent: ureq::Agent,
+}
+
+impl Fetcher {
+  pub fn new(base_url: &str, session: &str) -> Fetcher {
+    Fetcher {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      session: session.trim().to_string(),
+      agent: agent(),
+    }
+  }
+
+  pub fn from_env() -> Option<Fetcher> {
+    let (base_url, session) = from_env()?;
+    Some(Fetcher::new(&base_url, &session))
+  }
+
+  pub fn input_url(&self, year: u16, day: u8) -> String {
+    format!("{}/{}/day/{}/input", self.base_url, year, day)
+  }
+
+  pub fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+    let url = self.input_url(year, day);
+    let response = self
+      .agent
+      .get(&url)
+      .set("Cookie", &format!("session={}", self.session))
+      .call();
+
+    match response {
+      Ok(response) if response.status() == 200 => response
+        .into_string()
+        .map_err(|e| format!("cannot read {}: {}", url, e)),
+      // adventofcode.com sends a redirect to the login page when the session has expired
+      Ok(response) if (300..400).contains(&response.status()) => Err(format!(
+        "{} redirected; is the session token still valid?",
+        url
+      )),
+      Ok(response) => Err(format!("{} returned {}", url, response.status())),
+      Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(401, _)) => {
+        Err(format!("{} rejected the session token", url))
+      }
+      Err(ureq::Error::Status(404, _)) => Err(format!("{} isn't available (yet?)", url)),
+      Err(ureq::Error::Status(status, _)) => Err(format!("{} returned {}", url, status)),
+      Err(e) => Err(format!("cannot fetch {}: {}", url, e)),
+    }
+  }
+}
+
+// doesn't follow redirects, since adventofcode.com redirects to the login page when the session
+// has expired
+pub(crate) fn agent() -> ureq::Agent {
+  ureq::AgentBuilder::new()
+    .timeout(Duration::from_secs(30))
+    .redirects(0)
+    .user_agent(concat!(
+      "github.com/krisalyssa/advent-of-code-2020 ",
+      env!("CARGO_PKG_VERSION")
+    ))
+    .build()
+}
+
+// the base URL and session token: AOC_SESSION holds the session token, and AOC_BASE_URL can point
+// somewhere other than BASE_URL
+pub(crate) fn from_env() -> Option<(String, String)> {
+  let session = env::var("AOC_SESSION")
+    .ok()
+    .filter(|s| !s.trim().is_empty())?;
+  let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+
+  Some((base_url, session))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::mock::MockServer;
+  use crate::scratch_path;
+
+  fn scratch_cache() -> InputCache {
+    InputCache::new(scratch_path("inputs"))
+  }
+
+  #[test]
+  fn test_cache_path() {
+    let cache = InputCache::new("data");
+    assert_eq!(
+      cache.path(2020, 7),
+      PathBuf::from("data/2020/day-07-input.txt")
+    );
+  }
+
+  #[test]
+  fn test_cache_store() {
+    let cache = scratch_cache();
+    assert!(!cache.contains(2020, 1));
+
+    let path = cache.store(2020, 1, "1721\n979\n").unwrap();
+    assert!(cache.contains(2020, 1));
+    assert!(!cache.contains(2019, 1));
+    assert_eq!(fs::read_to_string(path).unwrap(), "1721\n979\n");
+
+    cache.store(2020, 2, "").unwrap();
+    assert!(!cache.contains(2020, 2));
+  }
+
+  #[test]
+  fn test_fetch() {
+    let server = MockServer::start(vec![(200, "0,3,6\n")]);
+    let fetcher = Fetcher::new(&format!("{}/", server.url), "cafebabe\n");
+
+    assert_eq!(fetcher.fetch(2020, 15), Ok("0,3,6\n".to_string()));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2020/day/15/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=cafebabe\r\n"));
+  }
+
+  #[test]
+  fn test_fetch_errors() {
+    let server = MockServer::start(vec![
+      (
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+      ),
+      (404, "Not Found"),
+      (302, ""),
+      (500, "oops"),
+    ]);
+    let fetcher = Fetcher::new(&server.url, "expired");
+    let url = fetcher.input_url(2020, 25);
+
+    assert_eq!(
+      fetcher.fetch(2020, 25),
+      Err(format!("{} rejected the session token", url))
+    );
+    assert_eq!(
+      fetcher.fetch(2020, 25),
+      Err(format!("{} isn't available (yet?)", url))
+    );
+    assert_eq!(
+      fetcher.fetch(2020, 25),
+      Err(format!(
+        "{} redirected; is the session token still valid?",
+        url
+      ))
+    );
+    assert_eq!(
+      fetcher.fetch(2020, 25),
+      Err(format!("{} returned 500", url))
+    );
+  }
+
+  #[test]
+  fn test_fetch_missing() {
+    // only one response, so a second request would never be answered
+    let server = MockServer::start(vec![(200, "FBFBBFFRLR\n")]);
+    let fetcher = Fetcher::new(&server.url, "cafebabe");
+    let cache = scratch_cache();
+
+    let path = cache.fetch_missing(2020, 5, &fetcher).unwrap();
+    assert_eq!(path, cache.path(2020, 5));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "FBFBBFFRLR\n");
+
+    assert_eq!(cache.fetch_missing(2020, 5, &fetcher), Ok(path));
+    assert_eq!(server.requests().len(), 1);
+  }
+}
@@ -0,0 +1,265 @@
+use std::ops::ControlFlow;
+
+// k of the numbers that add up to a target: where they are in the numbers, in increasing order,
+// and the numbers at those places
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tuple {
+  pub indices: Vec<usize>,
+  pub values: Vec<i64>,
+}
+
+// the first tuple the search comes across, if there are any; the search sorts the numbers and
+// walks two pointers in from either end for the last two of the k, so it takes O(n^(k-1)) time
+// rather than the O(n^k) of trying every combination
+pub fn find(numbers: &[i64], k: usize, target: i64) -> Option<Tuple> {
+  let mut found = None;
+  search(numbers, k, target, &mut |tuple| {
+    found = Some(tuple);
+    ControlFlow::Break(())
+  });
+
+  found
+}
+
+// every tuple, ordered by their indices; equal numbers in different places make different tuples
+pub fn find_all(numbers: &[i64], k: usize, target: i64) -> Vec<Tuple> {
+  let mut all = vec![];
+  search(numbers, k, target, &mut |tuple| {
+    all.push(tuple);
+    ControlFlow::Continue(())
+  });
+
+  all.sort_by(|a, b| a.indices.cmp(&b.indices));
+  all
+}
+
+fn search(numbers: &[i64], k: usize, target: i64, visit: &mut dyn FnMut(Tuple) -> ControlFlow<()>) {
+  // widened so that no sum of i64s can overflow
+  let mut sorted: Vec<(i128, usize)> = numbers
+    .iter()
+    .enumerate()
+    .map(|(ix, &number)| (number.into(), ix))
+    .collect();
+  sorted.sort_unstable();
+
+  let mut chosen = Vec::with_capacity(k);
+  let _ = choose(&sorted, k, target.into(), &mut chosen, &mut |chosen| {
+    let mut indices = chosen.to_vec();
+    indices.sort_unstable();
+    let values = indices.iter().map(|&ix| numbers[ix]).collect();
+    visit(Tuple { indices, values })
+  });
+}
+
+// every way of picking k of the sorted numbers that adds up to target, each picked by its place
+// in sorted, so that each set of indices comes up once however many of the numbers are equal
+fn choose(
+  sorted: &[(i128, usize)],
+  k: usize,
+  target: i128,
+  chosen: &mut Vec<usize>,
+  visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+  if k > sorted.len() {
+    return ControlFlow::Continue(());
+  }
+
+  match k {
+    0 if target == 0 => visit(chosen),
+    0 => ControlFlow::Continue(()),
+    1 => {
+      let from = sorted.partition_point(|&(value, _)| value < target);
+      for &(value, ix) in sorted[from..].iter() {
+        if value != target {
+          break;
+        }
+        pick(chosen, &[ix], visit)?;
+      }
+      ControlFlow::Continue(())
+    }
+    2 => pairs(sorted, target, chosen, visit),
+    _ => {
+      let largest: i128 = sorted[sorted.len() - (k - 1)..]
+        .iter()
+        .map(|&(value, _)| value)
+        .sum();
+      for first in 0..=sorted.len() - k {
+        let value = sorted[first].0;
+        // nothing from here on can be small enough
+        let smallest: i128 = sorted[first..first + k]
+          .iter()
+          .map(|&(value, _)| value)
+          .sum();
+        if smallest > target {
+          break;
+        }
+        if value + largest < target {
+          continue;
+        }
+
+        chosen.push(sorted[first].1);
+        let flow = choose(&sorted[first + 1..], k - 1, target - value, chosen, visit);
+        chosen.pop();
+        flow?;
+      }
+      ControlFlow::Continue(())
+    }
+  }
+}
+
+// the two-pointer walk: every pair of places in sorted whose numbers add up to target
+fn pairs(
+  sorted: &[(i128, usize)],
+  target: i128,
+  chosen: &mut Vec<usize>,
+  visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+  let (mut lo, mut hi) = (0, sorted.len() - 1);
+
+  while lo < hi {
+    let (low, high) = (sorted[lo].0, sorted[hi].0);
+    let sum = low + high;
+    if sum < target {
+      lo += 1;
+    } else if sum > target {
+      hi -= 1;
+    } else if low == high {
+      // everything from lo to hi is the same number, so any two of them will do
+      for a in lo..=hi {
+        for b in a + 1..=hi {
+          pick(chosen, &[sorted[a].1, sorted[b].1], visit)?;
+        }
+      }
+      break;
+    } else {
+      // every copy of the low number goes with every copy of the high one
+      let lo_end = lo
+        + sorted[lo..]
+          .iter()
+          .take_while(|&&(value, _)| value == low)
+          .count();
+      let hi_start = hi + 1
+        - sorted[..=hi]
+          .iter()
+          .rev()
+          .take_while(|&&(value, _)| value == high)
+          .count();
+      for a in lo..lo_end {
+        for b in hi_start..=hi {
+          pick(chosen, &[sorted[a].1, sorted[b].1], visit)?;
+        }
+      }
+      lo = lo_end;
+      hi = hi_start - 1;
+    }
+  }
+
+  ControlFlow::Continue(())
+}
+
+fn pick(
+  chosen: &mut Vec<usize>,
+  indices: &[usize],
+  visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+  chosen.extend_from_slice(indices);
+  let flow = visit(chosen);
+  chosen.truncate(chosen.len() - indices.len());
+  flow
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use itertools::Itertools;
+
+  const EXAMPLE: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+  fn indices(tuples: &[Tuple]) -> Vec<Vec<usize>> {
+    tuples.iter().map(|tuple| tuple.indices.clone()).collect()
+  }
+
+  // what trying every combination finds
+  fn brute_force(numbers: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    (0..numbers.len())
+      .combinations(k)
+      .filter(|ixs| ixs.iter().map(|&ix| numbers[ix]).sum::<i64>() == target)
+      .collect()
+  }
+
+  #[test]
+  fn test_find() {
+    assert_eq!(
+      find(&EXAMPLE, 2, 2020),
+      Some(Tuple {
+        indices: vec![0, 3],
+        values: vec![1721, 299]
+      })
+    );
+    assert_eq!(
+      find(&EXAMPLE, 3, 2020),
+      Some(Tuple {
+        indices: vec![1, 2, 4],
+        values: vec![979, 366, 675]
+      })
+    );
+    assert_eq!(find(&EXAMPLE, 2, 2021), None);
+    assert_eq!(find(&EXAMPLE, 7, 2020), None);
+  }
+
+  #[test]
+  fn test_small_k() {
+    assert_eq!(
+      indices(&find_all(&EXAMPLE, 0, 0)),
+      vec![Vec::<usize>::new()]
+    );
+    assert!(find_all(&EXAMPLE, 0, 1).is_empty());
+    assert_eq!(indices(&find_all(&[5, 3, 5], 1, 5)), vec![vec![0], vec![2]]);
+    assert!(find_all(&[], 2, 0).is_empty());
+  }
+
+  #[test]
+  fn test_duplicates() {
+    // a number can only be used as often as it appears
+    assert!(find_all(&[1010, 1], 2, 2020).is_empty());
+    assert_eq!(
+      indices(&find_all(&[1010, 3, 1010, 1010], 2, 2020)),
+      vec![vec![0, 2], vec![0, 3], vec![2, 3]]
+    );
+    assert_eq!(
+      indices(&find_all(&[1, 2, 1, 2], 2, 3)),
+      vec![vec![0, 1], vec![0, 3], vec![1, 2], vec![2, 3]]
+    );
+  }
+
+  #[test]
+  fn test_negatives() {
+    assert_eq!(
+      indices(&find_all(&[-1, 0, 1, 2, -1, -4], 3, 0)),
+      vec![vec![0, 1, 2], vec![0, 3, 4], vec![1, 2, 4]]
+    );
+    assert_eq!(
+      find(&[i64::MAX, i64::MAX, i64::MIN], 3, i64::MAX - 1),
+      Some(Tuple {
+        indices: vec![0, 1, 2],
+        values: vec![i64::MAX, i64::MAX, i64::MIN]
+      })
+    );
+  }
+
+  #[test]
+  fn test_against_brute_force() {
+    let numbers = [3, -2, 7, 0, 3, -5, 8, 1, -2, 4, 6, 0];
+    for k in 1..=5 {
+      for target in -8..=16 {
+        assert_eq!(
+          indices(&find_all(&numbers, k, target)),
+          brute_force(&numbers, k, target),
+          "k = {}, target = {}",
+          k,
+          target
+        );
+      }
+    }
+  }
+}
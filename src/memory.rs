@@ -0,0 +1,199 @@
+use serde_json::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// the system allocator, counting what each thread allocates; nothing is counted unless it's
+// installed with
+//
+//   #[global_allocator]
+//   static ALLOCATOR: CountingAllocator = CountingAllocator;
+//
+// which the aoc runner does when built with --features count-allocations
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+  static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+  static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+  // signed, since a thread can free memory that another thread allocated
+  static LIVE: Cell<i64> = const { Cell::new(0) };
+  static PEAK: Cell<i64> = const { Cell::new(0) };
+}
+
+fn allocated(size: usize) {
+  if !INSTALLED.load(Ordering::Relaxed) {
+    INSTALLED.store(true, Ordering::Relaxed);
+  }
+  // try_with, because the thread's counters may already have been torn down when it exits
+  let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+  let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+  let _ = LIVE.try_with(|live| {
+    live.set(live.get() + size as i64);
+    let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+  });
+}
+
+fn freed(size: usize) {
+  let _ = LIVE.try_with(|live| live.set(live.get() - size as i64));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+    if !ptr.is_null() {
+      allocated(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc_zeroed(layout);
+    if !ptr.is_null() {
+      allocated(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+    freed(layout.size());
+  }
+
+  // counted as a new allocation of the new size, as if it were a copy
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let new_ptr = System.realloc(ptr, layout, new_size);
+    if !new_ptr.is_null() {
+      freed(layout.size());
+      allocated(new_size);
+    }
+    new_ptr
+  }
+}
+
+// what a step allocated while it ran
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Memory {
+  pub allocations: u64,
+  pub bytes: u64,
+  // the most it had allocated and not yet freed at any one time
+  pub peak: u64,
+}
+
+impl Memory {
+  pub fn to_json(&self) -> serde_json::Value {
+    json!({
+      "allocations": self.allocations,
+      "bytes": self.bytes,
+      "peak_bytes": self.peak,
+    })
+  }
+}
+
+impl fmt::Display for Memory {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{} allocations, {} allocated, {} peak",
+      self.allocations,
+      format_bytes(self.bytes),
+      format_bytes(self.peak)
+    )
+  }
+}
+
+// true once anything has gone through CountingAllocator
+pub fn is_counting() -> bool {
+  INSTALLED.load(Ordering::Relaxed)
+}
+
+// what f allocates on this thread, or None if allocations aren't being counted; calls to
+// count_allocations() can't be nested
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+  let allocations = ALLOCATIONS.with(Cell::get);
+  let bytes = ALLOCATED.with(Cell::get);
+  let live = LIVE.with(Cell::get);
+  PEAK.with(|peak| peak.set(live));
+
+  let result = f();
+
+  let memory = Memory {
+    allocations: ALLOCATIONS.with(Cell::get) - allocations,
+    bytes: ALLOCATED.with(Cell::get) - bytes,
+    peak: (PEAK.with(Cell::get) - live) as u64,
+  };
+  (result, if is_counting() { Some(memory) } else { None })
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+  let units = ["B", "KiB", "MiB", "GiB"];
+  let mut value = bytes as f64;
+  let mut unit = 0;
+  while value >= 1024.0 && unit < units.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+
+  if unit == 0 {
+    format!("{} B", bytes)
+  } else {
+    format!("{:.1} {}", value, units[unit])
+  }
+}
+
+// the tests count allocations too
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_count_allocations() {
+    let (_, memory) = count_allocations(|| {
+      let small: Vec<u64> = Vec::with_capacity(4);
+      let big: Vec<u8> = Vec::with_capacity(1000);
+      drop(big);
+      let bigger: Vec<u8> = Vec::with_capacity(800);
+      (small, bigger)
+    });
+    assert_eq!(
+      memory,
+      Some(Memory {
+        allocations: 3,
+        bytes: 32 + 1000 + 800,
+        peak: 32 + 1000,
+      })
+    );
+
+    let (sum, memory) = count_allocations(|| (1..=10).sum::<u32>());
+    assert_eq!(sum, 55);
+    assert_eq!(memory, Some(Memory::default()));
+  }
+
+  #[test]
+  fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(480 * 1024 * 1024), "480.0 MiB");
+    assert_eq!(format_bytes(3 << 40), "3072.0 GiB");
+  }
+
+  #[test]
+  fn test_display() {
+    let memory = Memory {
+      allocations: 12,
+      bytes: 4096,
+      peak: 100,
+    };
+    assert_eq!(
+      memory.to_string(),
+      "12 allocations, 4.0 KiB allocated, 100 B peak"
+    );
+  }
+}
@@ -0,0 +1,78 @@
+use std::io::prelude::*;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// a stand-in for adventofcode.com in tests: answers each connection with the next canned
+// (status, body) response, and remembers the requests it was sent
+pub struct MockServer {
+  pub url: String,
+  requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+  pub fn start(responses: Vec<(u16, &'static str)>) -> MockServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+      for (status, body) in responses {
+        let (stream, _) = listener.accept().unwrap();
+        respond(stream, status, body, &seen);
+      }
+    });
+
+    MockServer { url, requests }
+  }
+
+  // every request received so far, as the request line, headers and body
+  pub fn requests(&self) -> Vec<String> {
+    self.requests.lock().unwrap().clone()
+  }
+}
+
+fn respond(stream: TcpStream, status: u16, body: &str, seen: &Mutex<Vec<String>>) {
+  let mut reader = BufReader::new(stream);
+  let mut request = String::new();
+  let mut content_length = 0;
+
+  loop {
+    let mut line = String::new();
+    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+      break;
+    }
+    if let Some((name, value)) = line.split_once(':') {
+      if name.eq_ignore_ascii_case("content-length") {
+        content_length = value.trim().parse().unwrap();
+      }
+    }
+    request += &line;
+  }
+
+  let mut content = vec![0; content_length];
+  reader.read_exact(&mut content).unwrap();
+  request += "\r\n";
+  request += &String::from_utf8_lossy(&content);
+  seen.lock().unwrap().push(request);
+
+  let reason = match status {
+    200 => "OK",
+    302 => "Found",
+    400 => "Bad Request",
+    404 => "Not Found",
+    _ => "Whatever",
+  };
+  let mut stream = reader.into_inner();
+  write!(
+    stream,
+    "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+    status,
+    reason,
+    body.len(),
+    body
+  )
+  .unwrap();
+}
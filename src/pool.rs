@@ -0,0 +1,164 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+// how many threads to use when nobody says otherwise
+pub fn default_threads() -> usize {
+  thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// calls work on every item using up to threads worker threads, and hands the results to done in
+// the same order as items; each result is handed over as soon as it and every one before it is
+// ready. with one thread (or one item) everything happens on the calling thread, one at a time.
+// if work panics on an item, done gets the panic's message for it instead of a result, and the
+// other items carry on
+pub fn run_in_order<T, R, W, D>(items: Vec<T>, threads: usize, work: W, mut done: D)
+where
+  T: Send,
+  R: Send,
+  W: Fn(T) -> R + Sync,
+  D: FnMut(Result<R, String>),
+{
+  let work = |item| panic::catch_unwind(AssertUnwindSafe(|| work(item))).map_err(message);
+  let threads = threads.max(1).min(items.len());
+  if threads <= 1 {
+    items.into_iter().for_each(|item| done(work(item)));
+    return;
+  }
+
+  // each worker takes the next item nobody has started on yet
+  let count = items.len();
+  let items: Vec<Mutex<Option<T>>> = items
+    .into_iter()
+    .map(|item| Mutex::new(Some(item)))
+    .collect();
+  let next = AtomicUsize::new(0);
+  let (sender, receiver) = mpsc::channel();
+
+  thread::scope(|scope| {
+    for _ in 0..threads {
+      let sender = sender.clone();
+      let (items, next, work) = (&items, &next, &work);
+      scope.spawn(move || loop {
+        let ix = next.fetch_add(1, Ordering::SeqCst);
+        let item = match items.get(ix) {
+          Some(item) => item.lock().unwrap().take().unwrap(),
+          None => break,
+        };
+        if sender.send((ix, work(item))).is_err() {
+          break;
+        }
+      });
+    }
+    drop(sender);
+
+    let mut finished: BTreeMap<usize, Result<R, String>> = BTreeMap::new();
+    let mut expected = 0;
+    for (ix, result) in receiver.iter().take(count) {
+      finished.insert(ix, result);
+      while let Some(result) = finished.remove(&expected) {
+        done(result);
+        expected += 1;
+      }
+    }
+  });
+}
+
+// what a panic was called with, which is almost always a string
+fn message(payload: Box<dyn Any + Send>) -> String {
+  match payload.downcast::<String>() {
+    Ok(message) => *message,
+    Err(payload) => match payload.downcast::<&str>() {
+      Ok(message) => message.to_string(),
+      Err(_) => "panicked".to_string(),
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  #[test]
+  fn test_run_in_order() {
+    for threads in 1..=4 {
+      let mut results = vec![];
+      // the later items finish first
+      run_in_order(
+        (0..8).collect(),
+        threads,
+        |n: u64| {
+          thread::sleep(Duration::from_millis(8 - n));
+          n * n
+        },
+        |result| results.push(result.unwrap()),
+      );
+      assert_eq!(results, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+  }
+
+  #[test]
+  fn test_run_in_order_with_a_panic() {
+    for threads in 1..=4 {
+      let mut results = vec![];
+      run_in_order(
+        (0..6).collect(),
+        threads,
+        |n: u64| {
+          if n == 2 {
+            panic!("no twos");
+          }
+          if n == 4 {
+            panic!("no {}s", n);
+          }
+          n
+        },
+        |result| results.push(result),
+      );
+      assert_eq!(
+        results,
+        vec![
+          Ok(0),
+          Ok(1),
+          Err("no twos".to_string()),
+          Ok(3),
+          Err("no 4s".to_string()),
+          Ok(5)
+        ]
+      );
+    }
+  }
+
+  #[test]
+  fn test_run_in_order_uses_threads() {
+    let serial_thread = thread::current().id();
+    let mut ids = vec![];
+    run_in_order(
+      vec![1, 2],
+      1,
+      |_: i32| thread::current().id(),
+      |id| ids.push(id.unwrap()),
+    );
+    assert!(ids.iter().all(|id| *id == serial_thread));
+
+    let mut ids = vec![];
+    run_in_order(
+      vec![1, 2],
+      2,
+      |_: i32| thread::current().id(),
+      |id| ids.push(id.unwrap()),
+    );
+    assert!(ids.iter().all(|id| *id != serial_thread));
+  }
+
+  #[test]
+  fn test_run_in_order_with_nothing_to_do() {
+    let mut called = false;
+    run_in_order(vec![], 4, |n: i32| n, |_| called = true);
+    assert!(!called);
+  }
+}
@@ -0,0 +1,272 @@
+use crate::{format_duration, Day, Part};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+  Text,
+  Json,
+  Csv,
+  Markdown,
+}
+
+impl FromStr for Format {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Format, String> {
+    match s {
+      "text" => Ok(Format::Text),
+      "json" => Ok(Format::Json),
+      "csv" => Ok(Format::Csv),
+      "markdown" | "md" => Ok(Format::Markdown),
+      _ => Err(format!(
+        "unknown format '{}' (expected text, json, csv or markdown)",
+        s
+      )),
+    }
+  }
+}
+
+impl Format {
+  // text can be printed a day at a time; the other formats need every day before they're complete
+  pub fn is_streaming(&self) -> bool {
+    *self == Format::Text
+  }
+
+  pub fn render(&self, days: &[Day]) -> String {
+    match self {
+      Format::Text => days.iter().map(text).collect(),
+      Format::Json => {
+        let values: Vec<serde_json::Value> = days.iter().map(Day::to_json).collect();
+        format!("{}\n", serde_json::to_string_pretty(&values).unwrap())
+      }
+      Format::Csv => csv(days),
+      Format::Markdown => markdown(days),
+    }
+  }
+}
+
+fn text(day: &Day) -> String {
+  let mut text = format!("{} day {:02}: {}\n", day.id.year, day.id.day, day);
+  if let Some(stats) = day.parse.as_ref().and_then(|parse| parse.stats.as_ref()) {
+    text += &format!("  parse: {}\n", stats);
+  }
+  for (n, part) in [(1, &day.part_1), (2, &day.part_2)].iter() {
+    if let Some(stats) = &part.stats {
+      text += &format!("  part {}: {}\n", n, stats);
+    }
+  }
+
+  text
+}
+
+fn csv(days: &[Day]) -> String {
+  let mut csv = String::from(
+    "year,day,parse_ns,part_1,part_1_status,part_1_ns,part_2,part_2_status,part_2_ns,total_ns,passed\n",
+  );
+  for day in days {
+    // empty for days that don't have a separate parse step
+    let parse_ns = day.parse.as_ref().map_or(String::new(), |parse| {
+      parse.duration().as_nanos().to_string()
+    });
+    csv += &format!(
+      "{},{},{},{},{},{},{}\n",
+      day.id.year,
+      day.id.day,
+      parse_ns,
+      csv_part(&day.part_1),
+      csv_part(&day.part_2),
+      day.duration().as_nanos(),
+      day.passed()
+    );
+  }
+
+  csv
+}
+
+fn csv_part(part: &Part) -> String {
+  let answer = match &part.result {
+    Ok(answer) => csv_field(&answer.to_string()),
+    Err(_) => String::new(),
+  };
+  format!(
+    "{},{},{}",
+    answer,
+    part.outcome(),
+    part.duration().as_nanos()
+  )
+}
+
+// quotes a field only if it has to be
+fn csv_field(field: &str) -> String {
+  if field.contains(&[',', '"', '\n'][..]) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field.to_string()
+  }
+}
+
+fn markdown(days: &[Day]) -> String {
+  let mut markdown = String::from(
+    "| Year | Day | Parse | Part 1 | Status | Time | Part 2 | Status | Time | Total |\n\
+     | ---: | --: | ----: | -----: | ------ | ---: | -----: | ------ | ---: | ----: |\n",
+  );
+  for day in days {
+    let parse = day
+      .parse
+      .as_ref()
+      .map_or(String::from("-"), |parse| format_duration(parse.duration()));
+    markdown += &format!(
+      "| {} | {} | {} | {} | {} | {} |\n",
+      day.id.year,
+      day.id.day,
+      parse,
+      markdown_part(&day.part_1),
+      markdown_part(&day.part_2),
+      format_duration(day.duration())
+    );
+  }
+
+  markdown
+}
+
+fn markdown_part(part: &Part) -> String {
+  let answer = match &part.result {
+    Ok(answer) => answer.to_string().replace('|', "\\|"),
+    Err(_) => String::from("-"),
+  };
+  format!(
+    "{} | {} | {}",
+    answer,
+    part.outcome(),
+    format_duration(part.duration())
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{solve, Answer, AocError, DayId, ExpectedAnswers, Input, Memory, Solution, Solver};
+  use std::time::Duration;
+
+  fn sample_days() -> Vec<Day> {
+    fn greeting(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::from("hello, \"world\""))
+    }
+
+    fn forty_two(_data: &[&str]) -> Result<Answer, AocError> {
+      Ok(Answer::Integer(42))
+    }
+
+    fn no_solution(_data: &[&str]) -> Result<Answer, AocError> {
+      Err(AocError::no_solution("nope"))
+    }
+
+    struct Sum;
+
+    impl<'a> Solution<'a> for Sum {
+      type Parsed = Vec<u64>;
+
+      fn parse(input: &Input<'a>) -> Result<Vec<u64>, AocError> {
+        input.numbers()
+      }
+
+      fn part_1(numbers: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(Answer::from(numbers.iter().sum::<u64>()))
+      }
+
+      fn part_2(numbers: &Vec<u64>) -> Result<Answer, AocError> {
+        Ok(Answer::from(numbers.len()))
+      }
+    }
+
+    let expected = ExpectedAnswers::from_toml(
+      2020,
+      "[day-01]\npart_2 = 42\n[day-02]\npart_1 = 41\n[day-03]\npart_1 = 6",
+    )
+    .unwrap();
+
+    let mut day_1 = Day::new(DayId::new(2020, 1), Solver::Parts(greeting, forty_two));
+    let mut day_2 = Day::new(DayId::new(2020, 2), Solver::Parts(forty_two, no_solution));
+    let mut day_3 = Day::new(DayId::new(2020, 3), Solver::Parsed(solve::<Sum>));
+    for day in [&mut day_1, &mut day_2, &mut day_3].iter_mut() {
+      day.run(&["1,2".to_string(), "3".to_string()]);
+      day.check(&expected);
+      day.part_1.duration = Duration::from_micros(1500);
+      day.part_2.duration = Duration::from_nanos(250);
+      day.duration = Duration::from_nanos(1_500_250);
+      // the tests count allocations, but how many there are depends on the platform
+      day.part_1.memory = None;
+      day.part_2.memory = None;
+    }
+    day_3.parse.as_mut().unwrap().duration = Duration::from_micros(100);
+    day_3.parse.as_mut().unwrap().memory = None;
+    day_3.part_1.memory = Some(Memory {
+      allocations: 2,
+      bytes: 2048,
+      peak: 1024,
+    });
+    day_3.duration = Duration::from_nanos(1_600_250);
+
+    vec![day_1, day_2, day_3]
+  }
+
+  #[test]
+  fn test_from_str() {
+    assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+    assert_eq!("md".parse::<Format>(), Ok(Format::Markdown));
+    assert!("xml".parse::<Format>().is_err());
+  }
+
+  #[test]
+  fn test_csv() {
+    assert_eq!(
+      Format::Csv.render(&sample_days()),
+      "year,day,parse_ns,part_1,part_1_status,part_1_ns,part_2,part_2_status,part_2_ns,total_ns,passed\n\
+       2020,1,,\"hello, \"\"world\"\"\",unknown,1500000,42,correct,250,1500250,true\n\
+       2020,2,,42,wrong,1500000,,failed,250,1500250,false\n\
+       2020,3,100000,6,correct,1500000,3,unknown,250,1600250,true\n"
+    );
+  }
+
+  #[test]
+  fn test_markdown() {
+    assert_eq!(
+      Format::Markdown.render(&sample_days()),
+      "| Year | Day | Parse | Part 1 | Status | Time | Part 2 | Status | Time | Total |\n\
+       | ---: | --: | ----: | -----: | ------ | ---: | -----: | ------ | ---: | ----: |\n\
+       | 2020 | 1 | - | hello, \"world\" | unknown | 1.500 ms | 42 | correct | 250 ns | 1.500 ms |\n\
+       | 2020 | 2 | - | 42 | wrong | 1.500 ms | - | failed | 250 ns | 1.500 ms |\n\
+       | 2020 | 3 | 100.000 µs | 6 | correct | 1.500 ms | 3 | unknown | 250 ns | 1.600 ms |\n"
+    );
+  }
+
+  #[test]
+  fn test_json() {
+    let json: serde_json::Value =
+      serde_json::from_str(&Format::Json.render(&sample_days())).unwrap();
+    assert_eq!(json[0]["year"], 2020);
+    assert_eq!(json[0]["day"], 1);
+    assert_eq!(json[0]["part_1"]["answer"], "hello, \"world\"");
+    assert_eq!(json[0]["part_2"]["status"], "correct");
+    assert_eq!(json[0]["passed"], true);
+    assert_eq!(json[1]["part_2"]["status"], "failed");
+    assert_eq!(json[1]["part_2"]["error"], "no solution: nope");
+    assert_eq!(json[1]["duration_ns"], 1_500_250);
+    assert_eq!(json[1]["passed"], false);
+    assert!(json[1].get("parse").is_none());
+    assert_eq!(json[2]["parse"]["duration_ns"], 100_000);
+    assert_eq!(json[2]["parse"]["error"], serde_json::Value::Null);
+    assert!(json[1]["part_1"].get("memory").is_none());
+    assert_eq!(json[2]["part_1"]["memory"]["peak_bytes"], 1024);
+  }
+
+  #[test]
+  fn test_text() {
+    let text = Format::Text.render(&sample_days());
+    assert!(text.starts_with("2020 day 01: part 1 = hello, \"world\" (unknown, 1.500 ms), "));
+    assert!(text.contains("\n2020 day 02: part 1 = 42 (wrong, expected 41, 1.500 ms), "));
+    assert!(text.contains(
+      "\n2020 day 03: parse = 100.000 µs, part 1 = 6 (correct, 1.500 ms, 2 allocations, 2.0 KiB allocated, 1.0 KiB peak), "
+    ));
+  }
+}
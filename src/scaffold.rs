@@ -0,0 +1,471 @@
+use crate::{data_dir, DayId};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// what a new day starts out as; NN is replaced by the day, with two digits, and YYYY by the year
+const TEMPLATE: &str = r#"use crate::{Answer, AocError};
+
+pub fn part_1(_data: &[&str]) -> Result<Answer, AocError> {
+  Err(AocError::no_solution("part 1 isn't solved yet"))
+}
+
+pub fn part_2(_data: &[&str]) -> Result<Answer, AocError> {
+  Err(AocError::no_solution("part 2 isn't solved yet"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // the example from the puzzle, once it has been pasted into example.txt
+  fn example() -> Vec<&'static str> {
+    include_str!("../../../data/YYYY/examples/day-NN/example.txt")
+      .lines()
+      .collect()
+  }
+
+  #[test]
+  #[ignore = "needs the example and its answer"]
+  fn test_part_1() {
+    assert_eq!(part_1(&example()), Ok(Answer::Integer(0)));
+  }
+
+  #[test]
+  #[ignore = "needs the example and its answer"]
+  fn test_part_2() {
+    assert_eq!(part_2(&example()), Ok(Answer::Integer(0)));
+  }
+}
+"#;
+
+// what a new year's module starts out as, before its first day is registered in it
+const YEAR_TEMPLATE: &str = "use crate::days::Entry;
+use crate::Solver;
+
+pub static REGISTRY: &[Entry] = &[
+];
+";
+
+const EXAMPLE_ANSWERS: &str = "# the example's answers, once they're known
+# part_1 = 0
+# part_2 = 0
+";
+
+// creates everything a new day needs under root (the top of the repository), and returns the
+// paths of the files it created; the first day of a year starts its module, src/days/yYYYY
+pub fn new_day(root: &Path, id: DayId) -> Result<Vec<PathBuf>, String> {
+  let day = id.day;
+  if !(1..=25).contains(&day) {
+    return Err(format!("there's no day {}; days go from 1 to 25", day));
+  }
+
+  let years = root.join("src/days/mod.rs");
+  let module = root.join(format!("src/days/y{}", id.year));
+  let registry = module.join("mod.rs");
+  let source = module.join(format!("day{:02}.rs", day));
+  if source.exists() {
+    return Err(format!("{} already exists", source.display()));
+  }
+  let new_year = !registry.exists();
+  let registered = if new_year {
+    register(YEAR_TEMPLATE, id)?
+  } else {
+    read(&registry).and_then(|text| register(&text, id))?
+  };
+  let years_registered = if new_year {
+    Some(read(&years).and_then(|text| register_year(&text, id.year))?)
+  } else {
+    None
+  };
+
+  let mut created = vec![];
+  write(&source, &from_template(id), &mut created)?;
+  if new_year {
+    write(&registry, &registered, &mut created)?;
+  } else {
+    fs::write(&registry, registered)
+      .map_err(|e| format!("cannot write {}: {}", registry.display(), e))?;
+  }
+  if let Some(years_registered) = years_registered {
+    fs::write(&years, years_registered)
+      .map_err(|e| format!("cannot write {}: {}", years.display(), e))?;
+  }
+
+  // the input and example stay empty until they're pasted in; see examples::discover()
+  let data = data_dir(id.year);
+  for (path, contents) in [
+    (id.input_path(), ""),
+    (format!("{}/examples/day-{:02}/example.txt", data, day), ""),
+    (
+      format!("{}/examples/day-{:02}/example.toml", data, day),
+      EXAMPLE_ANSWERS,
+    ),
+  ]
+  .iter()
+  {
+    let path = root.join(path);
+    if !path.exists() {
+      write(&path, contents, &mut created)?;
+    }
+  }
+
+  Ok(created)
+}
+
+fn from_template(id: DayId) -> String {
+  TEMPLATE
+    .replace("NN", &format!("{:02}", id.day))
+    .replace("YYYY", &id.year.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+  fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str, created: &mut Vec<PathBuf>) -> Result<(), String> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+  }
+  fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+  created.push(path.to_path_buf());
+
+  Ok(())
+}
+
+// adds the day's module and its entry in REGISTRY to the text of src/days/yYYYY/mod.rs, keeping
+// both in order of day
+fn register(registry: &str, id: DayId) -> Result<String, String> {
+  let day = id.day;
+  let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+  let module = format!("pub mod day{:02};", day);
+  if lines.contains(&module) {
+    return Err(format!("{} is already registered", id));
+  }
+  let start = lines
+    .iter()
+    .position(|line| line.starts_with("pub static REGISTRY"))
+    .ok_or("cannot find REGISTRY")?;
+  let modules: Vec<usize> = (0..lines.len())
+    .filter(|ix| lines[*ix].starts_with("pub mod day"))
+    .collect();
+  match modules.iter().find(|ix| lines[**ix] > module) {
+    Some(ix) => lines.insert(*ix, module),
+    None => match modules.last() {
+      Some(ix) => lines.insert(ix + 1, module),
+      // the year's first day
+      None => lines
+        .splice(start..start, [module, String::new()])
+        .for_each(drop),
+    },
+  }
+
+  let start = lines
+    .iter()
+    .position(|line| line.starts_with("pub static REGISTRY"))
+    .ok_or("cannot find REGISTRY")?;
+  let end = start
+    + lines[start..]
+      .iter()
+      .position(|line| line == "];")
+      .ok_or("cannot find the end of REGISTRY")?;
+
+  // before the first entry for a later day, and any comment above it
+  let mut at = end;
+  for ix in start..end {
+    let registered = lines[ix]
+      .trim()
+      .strip_prefix("day: ")
+      .and_then(|n| n.trim_end_matches(',').parse::<u8>().ok());
+    match registered {
+      Some(n) if n == day => return Err(format!("{} is already registered", id)),
+      Some(n) if n > day => {
+        at = ix;
+        while lines[at].trim() != "Entry {" {
+          at -= 1;
+        }
+        while lines[at - 1].trim_start().starts_with("//") {
+          at -= 1;
+        }
+        break;
+      }
+      _ => (),
+    }
+  }
+  let entry = [
+    "  Entry {".to_string(),
+    format!("    year: {},", id.year),
+    format!("    day: {},", day),
+    format!(
+      "    solver: Solver::Parts(day{:02}::part_1, day{:02}::part_2),",
+      day, day
+    ),
+    "    input: None,".to_string(),
+    "  },".to_string(),
+  ];
+  lines.splice(at..at, entry.iter().cloned());
+
+  Ok(lines.join("\n") + "\n")
+}
+
+// adds a year's module and its REGISTRY to YEARS in the text of src/days/mod.rs, keeping both in
+// order of year
+fn register_year(years: &str, year: u16) -> Result<String, String> {
+  let mut lines: Vec<String> = years.lines().map(String::from).collect();
+
+  let module = format!("pub mod y{};", year);
+  if lines.contains(&module) {
+    return Err(format!("{} is already registered", year));
+  }
+  let modules: Vec<usize> = (0..lines.len())
+    .filter(|ix| lines[*ix].starts_with("pub mod y"))
+    .collect();
+  let at = match modules.iter().find(|ix| lines[**ix] > module) {
+    Some(ix) => *ix,
+    None => modules.last().ok_or("cannot find the years' modules")? + 1,
+  };
+  lines.insert(at, module);
+
+  // YEARS is on one line, or one year to a line if that would be too long
+  let start = lines
+    .iter()
+    .position(|line| line.starts_with("pub static YEARS"))
+    .ok_or("cannot find YEARS")?;
+  let end = start
+    + lines[start..]
+      .iter()
+      .position(|line| line.ends_with("];"))
+      .ok_or("cannot find the end of YEARS")?;
+  let mut registries: Vec<String> = lines[start..=end]
+    .join(" ")
+    .split(|c: char| !c.is_alphanumeric() && c != ':' && c != '_')
+    .filter(|word| word.ends_with("::REGISTRY"))
+    .map(String::from)
+    .collect();
+  registries.push(format!("y{}::REGISTRY", year));
+  registries.sort();
+
+  let one_line = format!(
+    "pub static YEARS: &[&[Entry]] = &[{}];",
+    registries.join(", ")
+  );
+  let years = if one_line.len() <= 100 {
+    vec![one_line]
+  } else {
+    let mut years = vec!["pub static YEARS: &[&[Entry]] = &[".to_string()];
+    years.extend(registries.iter().map(|registry| format!("  {},", registry)));
+    years.push("];".to_string());
+    years
+  };
+  lines.splice(start..=end, years);
+
+  Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::scratch_path;
+
+  const YEARS: &str = "use crate::{DayId, InputSource, Solver};
+
+pub mod y2020;
+
+pub static YEARS: &[&[Entry]] = &[y2020::REGISTRY];
+";
+
+  const REGISTRY: &str = "use crate::days::Entry;
+use crate::Solver;
+
+pub mod day01;
+pub mod day19_2;
+
+pub static REGISTRY: &[Entry] = &[
+  Entry {
+    year: 2020,
+    day: 1,
+    solver: Solver::Parts(day01::part_1, day01::part_2),
+    input: None,
+  },
+  // the second attempt at day 19
+  Entry {
+    year: 2020,
+    day: 19,
+    solver: Solver::Parts(day19_2::part_1, day19_2::part_2),
+    input: None,
+  },
+];
+";
+
+  #[test]
+  fn test_register() {
+    assert_eq!(
+      register(REGISTRY, DayId::new(2020, 7)),
+      Ok(
+        "use crate::days::Entry;
+use crate::Solver;
+
+pub mod day01;
+pub mod day07;
+pub mod day19_2;
+
+pub static REGISTRY: &[Entry] = &[
+  Entry {
+    year: 2020,
+    day: 1,
+    solver: Solver::Parts(day01::part_1, day01::part_2),
+    input: None,
+  },
+  Entry {
+    year: 2020,
+    day: 7,
+    solver: Solver::Parts(day07::part_1, day07::part_2),
+    input: None,
+  },
+  // the second attempt at day 19
+  Entry {
+    year: 2020,
+    day: 19,
+    solver: Solver::Parts(day19_2::part_1, day19_2::part_2),
+    input: None,
+  },
+];
+"
+        .to_string()
+      )
+    );
+
+    let registered = register(REGISTRY, DayId::new(2020, 20)).unwrap();
+    assert!(registered.contains("pub mod day19_2;\npub mod day20;\n"));
+    assert!(registered.ends_with(
+      "  Entry {\n    year: 2020,\n    day: 20,\n    solver: Solver::Parts(day20::part_1, day20::part_2),\n    input: None,\n  },\n];\n"
+    ));
+
+    assert_eq!(
+      register(REGISTRY, DayId::new(2020, 1)),
+      Err("2020 day 1 is already registered".to_string())
+    );
+    assert_eq!(
+      register(REGISTRY, DayId::new(2020, 19)),
+      Err("2020 day 19 is already registered".to_string())
+    );
+  }
+
+  #[test]
+  fn test_register_first_day() {
+    assert_eq!(
+      register(YEAR_TEMPLATE, DayId::new(2015, 3)),
+      Ok(
+        "use crate::days::Entry;
+use crate::Solver;
+
+pub mod day03;
+
+pub static REGISTRY: &[Entry] = &[
+  Entry {
+    year: 2015,
+    day: 3,
+    solver: Solver::Parts(day03::part_1, day03::part_2),
+    input: None,
+  },
+];
+"
+        .to_string()
+      )
+    );
+  }
+
+  #[test]
+  fn test_register_year() {
+    let registered = register_year(YEARS, 2015).unwrap();
+    assert_eq!(
+      registered,
+      "use crate::{DayId, InputSource, Solver};
+
+pub mod y2015;
+pub mod y2020;
+
+pub static YEARS: &[&[Entry]] = &[y2015::REGISTRY, y2020::REGISTRY];
+"
+    );
+
+    let mut registered = registered;
+    for year in 2016..=2019 {
+      registered = register_year(&registered, year).unwrap();
+    }
+    assert!(registered.ends_with(
+      "pub mod y2020;
+
+pub static YEARS: &[&[Entry]] = &[
+  y2015::REGISTRY,
+  y2016::REGISTRY,
+  y2017::REGISTRY,
+  y2018::REGISTRY,
+  y2019::REGISTRY,
+  y2020::REGISTRY,
+];
+"
+    ));
+    assert_eq!(
+      register_year(&register_year(&registered, 2021).unwrap(), 2021),
+      Err("2021 is already registered".to_string())
+    );
+  }
+
+  #[test]
+  fn test_template() {
+    let source = from_template(DayId::new(2020, 20));
+    assert!(source.contains("include_str!(\"../../../data/2020/examples/day-20/example.txt\")"));
+    assert!(!source.contains("NN"));
+    assert!(!source.contains("YYYY"));
+  }
+
+  #[test]
+  fn test_new_day() {
+    let root = scratch_path("scaffold");
+    fs::create_dir_all(root.join("src/days/y2020")).unwrap();
+    fs::write(root.join("src/days/mod.rs"), YEARS).unwrap();
+    fs::write(root.join("src/days/y2020/mod.rs"), REGISTRY).unwrap();
+
+    let created = new_day(&root, DayId::new(2020, 20)).unwrap();
+    assert_eq!(
+      created,
+      vec![
+        root.join("src/days/y2020/day20.rs"),
+        root.join("data/2020/day-20-input.txt"),
+        root.join("data/2020/examples/day-20/example.txt"),
+        root.join("data/2020/examples/day-20/example.toml"),
+      ]
+    );
+    assert_eq!(
+      fs::read_to_string(root.join("src/days/y2020/day20.rs")).unwrap(),
+      from_template(DayId::new(2020, 20))
+    );
+    assert!(fs::read_to_string(root.join("src/days/y2020/mod.rs"))
+      .unwrap()
+      .contains("pub mod day20;"));
+
+    assert_eq!(
+      new_day(&root, DayId::new(2020, 20)),
+      Err(format!(
+        "{} already exists",
+        root.join("src/days/y2020/day20.rs").display()
+      ))
+    );
+    assert!(new_day(&root, DayId::new(2020, 26)).is_err());
+
+    // the first day of another year
+    let created = new_day(&root, DayId::new(2015, 1)).unwrap();
+    assert_eq!(
+      created[..2],
+      [
+        root.join("src/days/y2015/day01.rs"),
+        root.join("src/days/y2015/mod.rs"),
+      ]
+    );
+    assert!(fs::read_to_string(root.join("src/days/mod.rs"))
+      .unwrap()
+      .contains("pub mod y2015;\npub mod y2020;\n"));
+    assert!(root.join("data/2015/examples/day-01/example.toml").exists());
+  }
+}
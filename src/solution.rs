@@ -0,0 +1,55 @@
+use crate::{measure, Answer, AocError, BenchConfig, Day, Input, Parse, PartFn};
+
+// a day that parses its input once and shares the result between both parts; 'a is the
+// lifetime of the input, so Parsed can borrow from it
+pub trait Solution<'a> {
+  type Parsed;
+
+  fn parse(input: &Input<'a>) -> Result<Self::Parsed, AocError>;
+  fn part_1(parsed: &Self::Parsed) -> Result<Answer, AocError>;
+  fn part_2(parsed: &Self::Parsed) -> Result<Answer, AocError>;
+
+  // parse and solve in one go, mostly for tests
+  fn run_part_1(data: &'a [&'a str]) -> Result<Answer, AocError> {
+    Self::part_1(&Self::parse(&Input::new(data))?)
+  }
+
+  fn run_part_2(data: &'a [&'a str]) -> Result<Answer, AocError> {
+    Self::part_2(&Self::parse(&Input::new(data))?)
+  }
+}
+
+pub type SolveFn = fn(&mut Day, &[&str], Option<&BenchConfig>);
+
+// how a day gets from its input to its answers
+#[derive(Clone, Copy)]
+pub enum Solver {
+  // each part works on the raw lines
+  Parts(PartFn, PartFn),
+  // see Solution; made with solve::<S>, as in Solver::Parsed(solve::<Day14>)
+  Parsed(SolveFn),
+}
+
+pub fn solve<S: for<'a> Solution<'a>>(day: &mut Day, data: &[&str], config: Option<&BenchConfig>) {
+  let settings = day.settings.clone();
+  let input = Input::new(data).with_settings(settings.as_deref());
+  let measured = measure(|| S::parse(&input), config);
+  day.finish_parse(Parse {
+    error: measured.result.as_ref().err().cloned(),
+    stats: measured.stats,
+    memory: measured.memory,
+    duration: measured.duration,
+  });
+
+  match measured.result {
+    Ok(parsed) => {
+      day.measure_part(1, || S::part_1(&parsed), config);
+      day.measure_part(2, || S::part_2(&parsed), config);
+    }
+    // the parts never ran
+    Err(e) => {
+      day.part_1.result = Err(e.clone());
+      day.part_2.result = Err(e);
+    }
+  }
+}
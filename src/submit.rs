@@ -0,0 +1,507 @@
+use crate::inputs::{self, BASE_URL};
+use regex::Regex;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// everything that's been submitted, so that it's never submitted twice
+pub const SUBMISSIONS: &str = "data/submissions.json";
+
+// how long adventofcode.com makes you wait after a wrong answer if it doesn't say
+const COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+  Correct,
+  TooHigh,
+  TooLow,
+  // wrong, without a hint as to which way
+  Wrong,
+  // it's too soon after the last answer for this puzzle; try again after this long
+  Wait(Duration),
+  // the part was solved some other way, e.g. by hand on the website
+  AlreadySolved,
+}
+
+impl Verdict {
+  fn name(&self) -> Option<&'static str> {
+    match self {
+      Verdict::Correct => Some("correct"),
+      Verdict::TooHigh => Some("too high"),
+      Verdict::TooLow => Some("too low"),
+      Verdict::Wrong => Some("wrong"),
+      Verdict::Wait(_) | Verdict::AlreadySolved => None,
+    }
+  }
+
+  fn from_name(name: &str) -> Option<Verdict> {
+    match name {
+      "correct" => Some(Verdict::Correct),
+      "too high" => Some(Verdict::TooHigh),
+      "too low" => Some(Verdict::TooLow),
+      "wrong" => Some(Verdict::Wrong),
+      _ => None,
+    }
+  }
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Verdict::Wait(wait) => write!(f, "too soon; try again in {}s", wait.as_secs()),
+      Verdict::AlreadySolved => write!(f, "already solved"),
+      verdict => write!(f, "{}", verdict.name().unwrap()),
+    }
+  }
+}
+
+// an answer that was sent, and what adventofcode.com made of it
+#[derive(Clone, Debug, PartialEq)]
+struct Attempt {
+  year: u16,
+  day: u8,
+  part: u8,
+  answer: String,
+  verdict: Verdict,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Log {
+  attempts: Vec<Attempt>,
+  // when each puzzle can next be answered, in seconds since the epoch
+  cooldowns: HashMap<(u16, u8), u64>,
+}
+
+impl Log {
+  fn load(path: &PathBuf) -> Result<Log, String> {
+    let text = match fs::read_to_string(path) {
+      Ok(text) => text,
+      Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Log::default()),
+      Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+
+    serde_json::from_str(&text)
+      .ok()
+      .as_ref()
+      .and_then(Log::from_json)
+      .ok_or(format!("{}: not a log of submissions", path.display()))
+  }
+
+  fn save(&self, path: &PathBuf) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+      fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    fs::write(path, format!("{:#}\n", self.to_json()))
+      .map_err(|e| format!("{}: {}", path.display(), e))
+  }
+
+  fn to_json(&self) -> serde_json::Value {
+    let mut cooldowns: Vec<_> = self.cooldowns.iter().collect();
+    cooldowns.sort();
+
+    json!({
+      "attempts": self.attempts.iter().map(|attempt| json!({
+        "year": attempt.year,
+        "day": attempt.day,
+        "part": attempt.part,
+        "answer": attempt.answer,
+        "verdict": attempt.verdict.name(),
+      })).collect::<Vec<_>>(),
+      "cooldowns": cooldowns.iter().map(|((year, day), until)| json!({
+        "year": year,
+        "day": day,
+        "until": until,
+      })).collect::<Vec<_>>(),
+    })
+  }
+
+  fn from_json(value: &serde_json::Value) -> Option<Log> {
+    let mut log = Log::default();
+
+    for attempt in value["attempts"].as_array()? {
+      log.attempts.push(Attempt {
+        year: attempt["year"].as_u64()? as u16,
+        day: attempt["day"].as_u64()? as u8,
+        part: attempt["part"].as_u64()? as u8,
+        answer: attempt["answer"].as_str()?.to_string(),
+        verdict: Verdict::from_name(attempt["verdict"].as_str()?)?,
+      });
+    }
+    for cooldown in value["cooldowns"].as_array()? {
+      log.cooldowns.insert(
+        (
+          cooldown["year"].as_u64()? as u16,
+          cooldown["day"].as_u64()? as u8,
+        ),
+        cooldown["until"].as_u64()?,
+      );
+    }
+
+    Some(log)
+  }
+
+  fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+    self
+      .attempts
+      .iter()
+      .filter(move |a| a.year == year && a.day == day && a.part == part)
+  }
+
+  // why answer can't be right, going by the answers that have already been sent
+  fn rules_out(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+    if let Some(correct) = self
+      .attempts(year, day, part)
+      .find(|a| a.verdict == Verdict::Correct)
+    {
+      return Some(format!(
+        "it's already solved; the answer was {}",
+        correct.answer
+      ));
+    }
+    if let Some(attempt) = self.attempts(year, day, part).find(|a| a.answer == answer) {
+      return Some(format!("it was already {}", attempt.verdict));
+    }
+
+    let number = answer.parse::<i128>().ok()?;
+    self.attempts(year, day, part).find_map(|attempt| {
+      let other = attempt.answer.parse::<i128>().ok()?;
+      match attempt.verdict {
+        Verdict::TooHigh if number > other => Some(format!("{} was already too high", other)),
+        Verdict::TooLow if number < other => Some(format!("{} was already too low", other)),
+        _ => None,
+      }
+    })
+  }
+}
+
+// sends answers to adventofcode.com (or wherever base_url points), and keeps a log of them in
+// a file so that nothing is sent that's already known to be wrong, or before the site would
+// accept it
+pub struct Submitter {
+  base_url: String,
+  session: String,
+  agent: ureq::Agent,
+  path: PathBuf,
+  log: Log,
+}
+
+impl Submitter {
+  pub fn new<P: Into<PathBuf>>(
+    base_url: &str,
+    session: &str,
+    path: P,
+  ) -> Result<Submitter, String> {
+    let path = path.into();
+    Ok(Submitter {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      session: session.trim().to_string(),
+      agent: inputs::agent(),
+      log: Log::load(&path)?,
+      path,
+    })
+  }
+
+  // the same settings as inputs::Fetcher::from_env()
+  pub fn from_env<P: Into<PathBuf>>(path: P) -> Result<Submitter, String> {
+    let (base_url, session) = inputs::from_env().ok_or(format!(
+      "AOC_SESSION has to be set to submit answers to {}",
+      BASE_URL
+    ))?;
+    Submitter::new(&base_url, &session, path)
+  }
+
+  pub fn answer_url(&self, year: u16, day: u8) -> String {
+    format!("{}/{}/day/{}/answer", self.base_url, year, day)
+  }
+
+  pub fn submit(&mut self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |since| since.as_secs());
+    self.submit_at(year, day, part, answer, now)
+  }
+
+  // now is in seconds since the epoch
+  fn submit_at(
+    &mut self,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+  ) -> Result<Verdict, String> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+      return Err("there's no answer to submit".to_string());
+    }
+    if let Some(reason) = self.log.rules_out(year, day, part, answer) {
+      return Err(format!("not submitting {}: {}", answer, reason));
+    }
+    match self.log.cooldowns.get(&(year, day)) {
+      Some(until) if *until > now => return Ok(Verdict::Wait(Duration::from_secs(until - now))),
+      _ => (),
+    }
+
+    let (verdict, cooldown) = self.post(year, day, part, answer)?;
+    if let Some(cooldown) = cooldown {
+      self
+        .log
+        .cooldowns
+        .insert((year, day), now + cooldown.as_secs());
+    }
+    if verdict.name().is_some() {
+      self.log.attempts.push(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+      });
+    }
+    self.log.save(&self.path)?;
+
+    Ok(verdict)
+  }
+
+  fn post(
+    &self,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+  ) -> Result<(Verdict, Option<Duration>), String> {
+    let url = self.answer_url(year, day);
+    let response = self
+      .agent
+      .post(&url)
+      .set("Cookie", &format!("session={}", self.session))
+      .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+    match response {
+      Ok(response) if response.status() == 200 => response
+        .into_string()
+        .map_err(|e| format!("cannot read {}: {}", url, e))
+        .and_then(|page| parse_response(&page)),
+      Ok(response) if (300..400).contains(&response.status()) => Err(format!(
+        "{} redirected; is the session token still valid?",
+        url
+      )),
+      Ok(response) => Err(format!("{} returned {}", url, response.status())),
+      Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(401, _)) => {
+        Err(format!("{} rejected the session token", url))
+      }
+      Err(ureq::Error::Status(status, _)) => Err(format!("{} returned {}", url, status)),
+      Err(e) => Err(format!("cannot submit to {}: {}", url, e)),
+    }
+  }
+}
+
+// what the page that comes back after submitting an answer says, and how long it says to wait
+// before the next one
+fn parse_response(page: &str) -> Result<(Verdict, Option<Duration>), String> {
+  let text = article(page);
+
+  if text.contains("That's the right answer") {
+    return Ok((Verdict::Correct, None));
+  }
+  if text.contains("You don't seem to be solving the right level") {
+    return Ok((Verdict::AlreadySolved, None));
+  }
+  if text.contains("You gave an answer too recently") {
+    let regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let wait = match regex.captures(&text) {
+      Some(captures) => {
+        let minutes = captures.get(1).map_or(Ok(0), |m| number(m.as_str()))?;
+        let seconds = number(&captures[2])?;
+        minutes
+          .checked_mul(60)
+          .and_then(|minutes| minutes.checked_add(seconds))
+          .map(Duration::from_secs)
+          .ok_or_else(|| format!("cannot wait {}m {}s", minutes, seconds))?
+      }
+      None => COOLDOWN,
+    };
+    return Ok((Verdict::Wait(wait), Some(wait)));
+  }
+  if text.contains("That's not the right answer") {
+    let verdict = if text.contains("your answer is too high") {
+      Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+      Verdict::TooLow
+    } else {
+      Verdict::Wrong
+    };
+    let regex = Regex::new(r"(?i)please wait (\w+) minutes?").unwrap();
+    let cooldown = regex
+      .captures(&text)
+      .and_then(|captures| minutes(&captures[1]))
+      .map_or(COOLDOWN, |minutes| Duration::from_secs(minutes * 60));
+    return Ok((verdict, Some(cooldown)));
+  }
+
+  Err(format!("don't know what to make of: {}", text))
+}
+
+// the part of the page that has the response in it, without the markup
+fn article(page: &str) -> String {
+  let start = page
+    .find("<article>")
+    .map_or(0, |ix| ix + "<article>".len());
+  let end = page[start..]
+    .find("</article>")
+    .map_or(page.len(), |ix| start + ix);
+  let tags = Regex::new(r"<[^>]*>").unwrap();
+
+  tags.replace_all(&page[start..end], "").trim().to_string()
+}
+
+// one of the numbers in a response
+fn number(digits: &str) -> Result<u64, String> {
+  digits
+    .parse()
+    .map_err(|e| format!("cannot read {} as a number: {}", digits, e))
+}
+
+fn minutes(word: &str) -> Option<u64> {
+  let words = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+  ];
+  word.parse().ok().or_else(|| {
+    words
+      .iter()
+      .position(|w| *w == word)
+      .map(|ix| ix as u64 + 1)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::mock::MockServer;
+  use crate::scratch_path;
+
+  const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving your vacation. <a href=\"/2020/day/7#part2\">[Continue to Part Two]</a></p></article></main>";
+  const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2020/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2020/day/7\">[Return to Day 7]</a></p></article></main>";
+  const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait 5 minutes before trying again.</p></article>";
+  const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 21s left to wait. <a href=\"/2020/day/7\">[Return to Day 7]</a></p></article>";
+  const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2020/day/7\">[Return to Day 7]</a></p></article>";
+
+  fn scratch_log() -> PathBuf {
+    scratch_path("submissions.json")
+  }
+
+  #[test]
+  fn test_parse_response() {
+    let minute = Some(Duration::from_secs(60));
+    assert_eq!(parse_response(CORRECT), Ok((Verdict::Correct, None)));
+    assert_eq!(parse_response(TOO_HIGH), Ok((Verdict::TooHigh, minute)));
+    assert_eq!(
+      parse_response(&TOO_HIGH.replace("too high", "too low")),
+      Ok((Verdict::TooLow, minute))
+    );
+    assert_eq!(
+      parse_response(WRONG),
+      Ok((Verdict::Wrong, Some(Duration::from_secs(300))))
+    );
+    let wait = Duration::from_secs(81);
+    assert_eq!(
+      parse_response(TOO_RECENTLY),
+      Ok((Verdict::Wait(wait), Some(wait)))
+    );
+    assert_eq!(
+      parse_response(&TOO_RECENTLY.replace("1m", "99999999999999999999m")),
+      Err(
+        "cannot read 99999999999999999999 as a number: number too large to fit in target type"
+          .to_string()
+      )
+    );
+    assert_eq!(
+      parse_response(&TOO_RECENTLY.replace("1m", "999999999999999999m")),
+      Err("cannot wait 999999999999999999m 21s".to_string())
+    );
+    assert_eq!(
+      parse_response(WRONG_LEVEL),
+      Ok((Verdict::AlreadySolved, None))
+    );
+    assert_eq!(
+      parse_response("<html><article><p>Huh?</p></article></html>"),
+      Err("don't know what to make of: Huh?".to_string())
+    );
+  }
+
+  #[test]
+  fn test_submit() {
+    let server = MockServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+    let path = scratch_log();
+    let mut submitter = Submitter::new(&server.url, "cafebabe", &path).unwrap();
+    let now = 1_607_317_200;
+
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "500", now),
+      Ok(Verdict::TooHigh)
+    );
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /2020/day/7/answer HTTP/1.1\r\n"));
+    assert!(requests[0].contains("\r\nCookie: session=cafebabe\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=500"));
+
+    // none of these get as far as the server
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "500", now + 100),
+      Err("not submitting 500: it was already too high".to_string())
+    );
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "501", now + 100),
+      Err("not submitting 501: 500 was already too high".to_string())
+    );
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "287", now + 10),
+      Ok(Verdict::Wait(Duration::from_secs(50)))
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "287", now + 60),
+      Ok(Verdict::Correct)
+    );
+    assert_eq!(
+      submitter.submit_at(2020, 7, 1, "287", now + 1000),
+      Err("not submitting 287: it's already solved; the answer was 287".to_string())
+    );
+    assert_eq!(server.requests().len(), 2);
+
+    // the log outlives the submitter
+    let submitter = Submitter::new(&server.url, "cafebabe", &path).unwrap();
+    assert_eq!(submitter.log.attempts.len(), 2);
+    assert_eq!(submitter.log.cooldowns.get(&(2020, 7)), Some(&(now + 60)));
+    assert_eq!(submitter.log, Log::load(&path).unwrap());
+  }
+
+  #[test]
+  fn test_submit_too_recently() {
+    let server = MockServer::start(vec![(200, TOO_RECENTLY), (400, "")]);
+    let path = scratch_log();
+    let mut submitter = Submitter::new(&server.url, "expired", &path).unwrap();
+    let now = 1_607_317_200;
+
+    let wait = Verdict::Wait(Duration::from_secs(81));
+    assert_eq!(submitter.submit_at(2020, 7, 2, "1", now), Ok(wait));
+    assert_eq!(
+      submitter.submit_at(2020, 7, 2, "1", now + 1),
+      Ok(Verdict::Wait(Duration::from_secs(80)))
+    );
+    // nothing was said about the answer itself, so it can be sent again
+    assert_eq!(
+      submitter.submit_at(2020, 7, 2, "1", now + 81),
+      Err(format!(
+        "{} rejected the session token",
+        submitter.answer_url(2020, 7)
+      ))
+    );
+    assert_eq!(server.requests().len(), 2);
+  }
+}
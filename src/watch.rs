@@ -0,0 +1,269 @@
+use crate::{format_duration, DayId};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+// the files a day's answers depend on: its source (including any later version of it, like
+// day19_2.rs), its input, wherever that is (see DayId::find_input()), and its settings
+pub fn sources(root: &Path, id: DayId) -> Result<Vec<PathBuf>, String> {
+  let dir = root.join(format!("src/days/y{}", id.year));
+  let name = format!("day{:02}", id.day);
+  let mut paths = vec![];
+
+  let entries = fs::read_dir(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+  for entry in entries {
+    let path = entry
+      .map_err(|e| format!("{}: {}", dir.display(), e))?
+      .path();
+    let stem = path
+      .file_stem()
+      .and_then(|stem| stem.to_str())
+      .unwrap_or_default();
+    let is_day = stem == name || stem.starts_with(&format!("{}_", name));
+    if is_day && path.extension().and_then(|extension| extension.to_str()) == Some("rs") {
+      paths.push(path);
+    }
+  }
+  if paths.is_empty() {
+    return Err(format!("there's no {}.rs in {}", name, dir.display()));
+  }
+
+  paths.sort();
+  paths.push(root.join(id.input_path()));
+  paths.extend(id.legacy_input_path().map(|path| root.join(path)));
+  paths.push(root.join(id.settings_path()));
+  Ok(paths)
+}
+
+// notices when files change, by polling when they were last modified
+pub struct Watcher {
+  paths: Vec<PathBuf>,
+  // None for a file that isn't there
+  seen: Vec<Option<SystemTime>>,
+}
+
+impl Watcher {
+  pub fn new(paths: Vec<PathBuf>) -> Watcher {
+    let seen = paths.iter().map(|path| modified(path)).collect();
+    Watcher { paths, seen }
+  }
+
+  pub fn paths(&self) -> &[PathBuf] {
+    &self.paths
+  }
+
+  // the files that have changed (or appeared, or gone away) since the last time
+  pub fn changed(&mut self) -> Vec<&Path> {
+    let mut changed = vec![];
+    for (path, seen) in self.paths.iter().zip(self.seen.iter_mut()) {
+      let now = modified(path);
+      if now != *seen {
+        *seen = now;
+        changed.push(path.as_path());
+      }
+    }
+
+    changed
+  }
+}
+
+// a file that can't be read counts as missing, so that it's changed once it can be read again
+fn modified(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+// one part of a run, as reported by aoc run --format json
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRun {
+  pub answer: Option<String>,
+  pub error: Option<String>,
+  // correct, wrong, unknown, failed or timed out; see Part::outcome()
+  pub status: String,
+  pub duration: Duration,
+}
+
+impl PartRun {
+  fn from_json(value: &serde_json::Value) -> Option<PartRun> {
+    Some(PartRun {
+      answer: value["answer"].as_str().map(String::from),
+      error: value["error"].as_str().map(String::from),
+      status: value["status"].as_str()?.to_string(),
+      duration: Duration::from_nanos(value["duration_ns"].as_u64()?),
+    })
+  }
+
+  fn result(&self) -> String {
+    match (&self.answer, &self.error) {
+      (Some(answer), _) => format!("{} ({})", answer, self.status),
+      (None, Some(error)) => format!("{} ({})", self.status, error),
+      (None, None) => self.status.clone(),
+    }
+  }
+}
+
+// a run of a day, as reported by aoc run --format json
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayRun {
+  pub id: DayId,
+  pub parts: [PartRun; 2],
+  pub duration: Duration,
+}
+
+impl DayRun {
+  pub fn from_json(value: &serde_json::Value) -> Option<DayRun> {
+    Some(DayRun {
+      id: DayId::new(value["year"].as_u64()? as u16, value["day"].as_u64()? as u8),
+      parts: [
+        PartRun::from_json(&value["part_1"])?,
+        PartRun::from_json(&value["part_2"])?,
+      ],
+      duration: Duration::from_nanos(value["duration_ns"].as_u64()?),
+    })
+  }
+
+  // the only day in the output of aoc run --format json
+  pub fn parse(output: &str) -> Result<DayRun, String> {
+    let value: serde_json::Value = serde_json::from_str(output).map_err(|e| e.to_string())?;
+    match value.as_array().map(Vec::as_slice) {
+      Some([day]) => DayRun::from_json(day).ok_or_else(|| "not a run of a day".to_string()),
+      _ => Err("expected a single day".to_string()),
+    }
+  }
+}
+
+// new's answers and timings, next to old's if there was an earlier run
+pub fn report(old: Option<&DayRun>, new: &DayRun) -> String {
+  let mut text = format!("{} day {:02}:\n", new.id.year, new.id.day);
+
+  for (ix, part) in new.parts.iter().enumerate() {
+    text += &format!(
+      "  part {}: {} in {}",
+      ix + 1,
+      part.result(),
+      format_duration(part.duration)
+    );
+    if let Some(old) = old.map(|old| &old.parts[ix]) {
+      if old.result() == part.result() {
+        text += &format!("; was {}", timing(old.duration, part.duration));
+      } else {
+        text += &format!(
+          "; was {} in {}",
+          old.result(),
+          format_duration(old.duration)
+        );
+      }
+    }
+    text += "\n";
+  }
+
+  text += &format!("  total: {}", format_duration(new.duration));
+  if let Some(old) = old {
+    text += &format!("; was {}", timing(old.duration, new.duration));
+  }
+
+  text + "\n"
+}
+
+fn timing(from: Duration, to: Duration) -> String {
+  if from.as_nanos() == 0 {
+    return format_duration(from);
+  }
+  let percent = (to.as_secs_f64() / from.as_secs_f64() - 1.0) * 100.0;
+  format!("{} ({:+.0}%)", format_duration(from), percent)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::scratch_path;
+  use serde_json::json;
+
+  fn part(answer: &str, status: &str, millis: u64) -> PartRun {
+    PartRun {
+      answer: Some(answer.to_string()),
+      error: None,
+      status: status.to_string(),
+      duration: Duration::from_millis(millis),
+    }
+  }
+
+  #[test]
+  fn test_sources() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(
+      sources(root, DayId::new(2020, 19)),
+      Ok(vec![
+        root.join("src/days/y2020/day19_2.rs"),
+        root.join("data/2020/day-19-input.txt"),
+        root.join("data/day-19-input.txt"),
+        root.join("data/2020/day-19-settings.toml"),
+      ])
+    );
+    assert_eq!(sources(root, DayId::new(2020, 1)).unwrap().len(), 4);
+    assert!(sources(root, DayId::new(2020, 25)).is_err());
+    assert!(sources(root, DayId::new(2015, 1)).is_err());
+  }
+
+  #[test]
+  fn test_watcher() {
+    let path = scratch_path("watch.txt");
+
+    let mut watcher = Watcher::new(vec![path.clone()]);
+    assert!(watcher.changed().is_empty());
+    fs::write(&path, "1\n").unwrap();
+    assert_eq!(watcher.changed(), vec![path.as_path()]);
+    assert!(watcher.changed().is_empty());
+    fs::remove_file(&path).unwrap();
+    assert_eq!(watcher.changed(), vec![path.as_path()]);
+  }
+
+  #[test]
+  fn test_parse() {
+    let output = json!([{
+      "year": 2020,
+      "day": 7,
+      "part_1": {"answer": "287", "error": null, "status": "correct", "duration_ns": 2_000_000},
+      "part_2": {"answer": null, "error": "no solution: nope", "status": "failed", "duration_ns": 500},
+      "duration_ns": 2_000_500,
+      "passed": false,
+    }]);
+    let run = DayRun::parse(&output.to_string()).unwrap();
+    assert_eq!(run.id, DayId::new(2020, 7));
+    assert_eq!(run.parts[0], part("287", "correct", 2));
+    assert_eq!(run.parts[1].result(), "failed (no solution: nope)");
+
+    assert!(DayRun::parse("[]").is_err());
+    assert!(DayRun::parse("error: could not compile").is_err());
+  }
+
+  #[test]
+  fn test_report() {
+    let old = DayRun {
+      id: DayId::new(2020, 7),
+      parts: [part("287", "correct", 20), part("48159", "unknown", 10)],
+      duration: Duration::from_millis(30),
+    };
+    assert_eq!(
+      report(None, &old),
+      "2020 day 07:
+  part 1: 287 (correct) in 20.000 ms
+  part 2: 48159 (unknown) in 10.000 ms
+  total: 30.000 ms
+"
+    );
+
+    let new = DayRun {
+      id: DayId::new(2020, 7),
+      parts: [part("287", "correct", 10), part("48160", "wrong", 10)],
+      duration: Duration::from_millis(20),
+    };
+    assert_eq!(
+      report(Some(&old), &new),
+      "2020 day 07:
+  part 1: 287 (correct) in 10.000 ms; was 20.000 ms (-50%)
+  part 2: 48160 (wrong) in 10.000 ms; was 48159 (unknown) in 10.000 ms
+  total: 20.000 ms; was 30.000 ms (-33%)
+"
+    );
+  }
+}